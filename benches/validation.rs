@@ -0,0 +1,82 @@
+//! Benchmarks comparing the single-pass and batch validators with the original
+//! multi-pass validator.
+
+#![doc(hidden)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use typeid_prefix::batch;
+use typeid_prefix::prelude::*;
+
+/// The multi-pass validator that predates the lookup table, kept as a baseline.
+fn legacy_check(input: &str) -> Result<(), ValidationError> {
+    if input.len() > 63 {
+        return Err(ValidationError::ExceedsMaxLength);
+    }
+    if input.is_empty() {
+        return Err(ValidationError::IsEmpty);
+    }
+    if !input.is_ascii() {
+        return Err(ValidationError::ContainsInvalidCharacters);
+    }
+    if input.starts_with('_') {
+        return Err(ValidationError::StartsWithUnderscore);
+    }
+    if input.ends_with('_') {
+        return Err(ValidationError::EndsWithUnderscore);
+    }
+    if !input.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return Err(ValidationError::InvalidStartCharacter);
+    }
+    if !input.ends_with(|c: char| c.is_ascii_lowercase()) {
+        return Err(ValidationError::InvalidEndCharacter);
+    }
+    if !input.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+        return Err(ValidationError::ContainsInvalidCharacters);
+    }
+    Ok(())
+}
+
+fn inputs() -> Vec<(&'static str, String)> {
+    vec![
+        ("short", "user".to_string()),
+        ("medium", "order_line_item_adjustment".to_string()),
+        ("max_length", "a".repeat(31) + "_" + &"b".repeat(31)),
+        ("invalid_middle", "order_line_item-adjustment".to_string()),
+    ]
+}
+
+fn bench_single(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single");
+    for (name, input) in inputs() {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("legacy", name), &input, |b, input| {
+            b.iter(|| legacy_check(black_box(input)).map(|()| input.clone()));
+        });
+        group.bench_with_input(BenchmarkId::new("table", name), &input, |b, input| {
+            b.iter(|| TypeIdPrefix::try_from(black_box(input.as_str())));
+        });
+    }
+    group.finish();
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let column: Vec<String> = inputs().into_iter().map(|(_, input)| input).cycle().take(10_000).collect();
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(column.len() as u64));
+    group.bench_function("legacy", |b| {
+        b.iter(|| black_box(&column).iter().map(|input| legacy_check(input)).collect::<Vec<_>>());
+    });
+    group.bench_function("check_many", |b| {
+        b.iter(|| batch::check_many(black_box(&column)));
+    });
+    group.bench_function("validate_many", |b| {
+        b.iter(|| batch::validate_many(black_box(&column)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_single, bench_batch);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+#![no_main]
+
+use std::sync::LazyLock;
+
+use libfuzzer_sys::fuzz_target;
+use regex::Regex;
+use typeid_prefix::TypeIdPrefix;
+
+/// The prefix grammar from the `TypeID` specification, written as a regular expression.
+static PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[a-z]([a-z_]{0,61}[a-z])?$").unwrap());
+
+fuzz_target!(|input: &str| {
+    assert_eq!(
+        TypeIdPrefix::try_from(input).is_ok(),
+        PREFIX.is_match(input),
+        "validator and regex disagree on {input:?}"
+    );
+});
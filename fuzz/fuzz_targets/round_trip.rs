@@ -0,0 +1,23 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use typeid_prefix::TypeIdPrefix;
+
+fuzz_target!(|input: (TypeIdPrefix, &str)| {
+    let (prefix, text) = input;
+
+    // FromStr and Display round-trip every valid prefix
+    let displayed = prefix.to_string();
+    assert_eq!(TypeIdPrefix::from_str(&displayed), Ok(prefix.clone()));
+
+    // Serde JSON round-trips every valid prefix
+    let json = serde_json::to_string(&prefix).unwrap();
+    assert_eq!(serde_json::from_str::<TypeIdPrefix>(&json).unwrap(), prefix);
+
+    // Deserializing arbitrary text succeeds exactly when FromStr does
+    let json = serde_json::to_string(text).unwrap();
+    let deserialized = serde_json::from_str::<TypeIdPrefix>(&json).ok();
+    assert_eq!(deserialized, TypeIdPrefix::from_str(text).ok());
+});
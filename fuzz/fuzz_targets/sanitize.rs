@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typeid_prefix::prelude::*;
+
+fuzz_target!(|input: &str| {
+    let sanitized = input.create_prefix_sanitized();
+
+    // The sanitized prefix is valid, or empty if nothing could be salvaged
+    if !sanitized.is_empty() {
+        assert!(TypeIdPrefix::try_from(sanitized.as_str()).is_ok());
+    }
+
+    // Sanitizing is canonicalization, which is idempotent
+    assert_eq!(TypeIdPrefix::canonicalize(input), sanitized);
+    assert_eq!(sanitized.as_str().create_prefix_sanitized(), sanitized);
+    assert_eq!(TypeIdPrefix::is_canonical(input), sanitized == input);
+
+    // Valid input is left unchanged
+    if let Ok(prefix) = TypeIdPrefix::try_from(input) {
+        assert_eq!(sanitized, prefix);
+    }
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typeid_prefix::{TypeIdPrefix, TypeIdPrefixRef};
+
+fuzz_target!(|data: &[u8]| {
+    // Bytes are validated without a UTF-8 pass, so they must agree with the &str path
+    let from_bytes = TypeIdPrefix::try_from_bytes(data);
+    let Ok(input) = std::str::from_utf8(data) else {
+        assert!(from_bytes.is_err());
+        return;
+    };
+
+    let from_str = TypeIdPrefix::try_from(input);
+    let from_string = TypeIdPrefix::try_from(input.to_string());
+    let from_ref = TypeIdPrefixRef::try_from(input);
+
+    assert_eq!(from_str, from_bytes);
+    assert_eq!(from_str, from_string);
+    assert_eq!(from_str.as_ref().map(TypeIdPrefix::as_str).map_err(|e| *e), from_ref.map(|r| r.as_str()));
+
+    if let Ok(prefix) = from_str {
+        assert_eq!(prefix.as_str(), input);
+        assert!(TypeIdPrefix::try_from(prefix.as_str()).is_ok());
+    }
+});
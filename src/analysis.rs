@@ -0,0 +1,209 @@
+//! Detection of reserved and easily confused prefixes.
+//!
+//! Prefixes that are valid on their own can still cause confusion when several teams share an
+//! ID space: `admin` or `system` may collide with internal conventions, and `user`, `users` and
+//! `usr` are easy to mix up. A [`PrefixAnalyzer`] looks at a set of prefixes and reports
+//! [`Finding`]s for review tooling to surface; it never rejects a prefix.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::analysis::{Finding, PrefixAnalyzer};
+//! use typeid_prefix::TypeIdPrefix;
+//!
+//! let prefixes: Vec<TypeIdPrefix> = ["user", "users", "usr", "admin"]
+//!     .iter()
+//!     .map(|p| TypeIdPrefix::try_from(*p).unwrap())
+//!     .collect();
+//!
+//! let findings = PrefixAnalyzer::new().analyze(&prefixes);
+//! assert_eq!(findings.len(), 3);
+//! assert!(matches!(&findings[0], Finding::Reserved { word, .. } if word == "admin"));
+//! assert!(matches!(&findings[1], Finding::SingularPlural { singular, plural } if singular == "user" && plural == "users"));
+//! assert!(matches!(&findings[2], Finding::SimilarSpelling { distance: 1, .. }));
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::TypeIdPrefix;
+
+/// Words reserved by [`PrefixAnalyzer::new`].
+pub const DEFAULT_RESERVED: &[&str] = &[
+    "admin", "api", "default", "internal", "null", "root", "system", "test", "undefined",
+];
+
+/// Something about a prefix, or a pair of prefixes, that is likely to cause confusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// The prefix, or one of its underscore-separated segments, is a reserved word.
+    Reserved {
+        /// The prefix containing the reserved word.
+        prefix: TypeIdPrefix,
+        /// The reserved word that matched.
+        word: String,
+    },
+
+    /// One prefix is the plural of another.
+    SingularPlural {
+        /// The singular form.
+        singular: TypeIdPrefix,
+        /// The plural form.
+        plural: TypeIdPrefix,
+    },
+
+    /// Two prefixes are within the analyzer's maximum edit distance of each other.
+    SimilarSpelling {
+        /// The prefix that appears first in the input.
+        first: TypeIdPrefix,
+        /// The prefix that appears second in the input.
+        second: TypeIdPrefix,
+        /// The Levenshtein distance between the two prefixes.
+        distance: usize,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reserved { prefix, word } => write!(f, "\"{prefix}\" uses the reserved word {word:?}"),
+            Self::SingularPlural { singular, plural } => {
+                write!(f, "\"{plural}\" is the plural of \"{singular}\"")
+            }
+            Self::SimilarSpelling { first, second, distance } => {
+                write!(f, "\"{first}\" and \"{second}\" differ by {distance} edit(s)")
+            }
+        }
+    }
+}
+
+/// Finds reserved words, singular/plural pairs and near-duplicate spellings in a set of prefixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixAnalyzer {
+    reserved: Vec<String>,
+    max_distance: usize,
+}
+
+impl Default for PrefixAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrefixAnalyzer {
+    /// Creates an analyzer using [`DEFAULT_RESERVED`] and a maximum edit distance of 1.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            reserved: DEFAULT_RESERVED.iter().map(ToString::to_string).collect(),
+            max_distance: 1,
+        }
+    }
+
+    /// Replaces the reserved words.
+    #[must_use]
+    pub fn reserved<I>(mut self, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.reserved = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the largest edit distance reported as [`Finding::SimilarSpelling`].
+    ///
+    /// A distance of 0 disables the check.
+    #[must_use]
+    pub const fn max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Analyzes `prefixes` and returns every finding.
+    ///
+    /// Reserved words are reported first, in input order, followed by pairs in the order their
+    /// first prefix appears. A pair that is a singular/plural pair is not also reported as a
+    /// similar spelling, and repeated prefixes are only considered once.
+    #[must_use]
+    pub fn analyze(&self, prefixes: &[TypeIdPrefix]) -> Vec<Finding> {
+        let mut unique: Vec<&TypeIdPrefix> = Vec::with_capacity(prefixes.len());
+        for prefix in prefixes {
+            if !unique.contains(&prefix) {
+                unique.push(prefix);
+            }
+        }
+
+        let mut findings: Vec<Finding> = unique
+            .iter()
+            .filter_map(|prefix| {
+                let word = self.reserved_word(prefix.as_str())?;
+                Some(Finding::Reserved { prefix: (*prefix).clone(), word: word.to_string() })
+            })
+            .collect();
+
+        for (i, first) in unique.iter().enumerate() {
+            for second in &unique[i + 1..] {
+                if let Some(finding) = self.compare(first, second) {
+                    findings.push(finding);
+                }
+            }
+        }
+
+        findings
+    }
+
+    fn reserved_word(&self, prefix: &str) -> Option<&str> {
+        self.reserved
+            .iter()
+            .find(|word| prefix.split('_').any(|segment| segment == word.as_str()))
+            .map(String::as_str)
+    }
+
+    fn compare(&self, first: &TypeIdPrefix, second: &TypeIdPrefix) -> Option<Finding> {
+        if is_plural_of(second.as_str(), first.as_str()) {
+            return Some(Finding::SingularPlural { singular: first.clone(), plural: second.clone() });
+        }
+        if is_plural_of(first.as_str(), second.as_str()) {
+            return Some(Finding::SingularPlural { singular: second.clone(), plural: first.clone() });
+        }
+
+        let distance = levenshtein(first.as_str(), second.as_str());
+        (distance <= self.max_distance).then(|| Finding::SimilarSpelling {
+            first: first.clone(),
+            second: second.clone(),
+            distance,
+        })
+    }
+}
+
+/// Returns `true` if `plural` is a regular English plural of `singular`.
+fn is_plural_of(plural: &str, singular: &str) -> bool {
+    let Some(suffix) = plural.strip_prefix(singular) else {
+        return singular
+            .strip_suffix('y')
+            .and_then(|stem| plural.strip_prefix(stem))
+            .is_some_and(|suffix| suffix == "ies");
+    };
+    suffix == "s" || suffix == "es"
+}
+
+/// Computes the Levenshtein distance between two ASCII strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = Vec::with_capacity(b.len() + 1);
+
+    for (i, &a_byte) in a.iter().enumerate() {
+        current.clear();
+        current.push(i + 1);
+        for (j, &b_byte) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_byte != b_byte);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
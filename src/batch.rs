@@ -0,0 +1,200 @@
+//! Batch validation of many `TypeID` prefixes at once.
+//!
+//! The functions in this module validate each input with a chunked scanner that classifies
+//! eight bytes at a time. They report exactly the same [`ValidationError`] variants as
+//! [`TypeIdPrefix::try_from`], and the results are returned in input order.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::batch;
+//! use typeid_prefix::ValidationError;
+//!
+//! let results = batch::check_many(["user", "Invalid", "order_item"]);
+//! assert_eq!(results, vec![Ok(()), Err(ValidationError::InvalidStartCharacter), Ok(())]);
+//! ```
+//!
+//! ## Parallel Batches
+//!
+//! When the `rayon` feature is enabled, `validate_batch` and `sanitize_batch` process
+//! large inputs in parallel on the rayon thread pool, built on the [`PrefixFactory`] methods.
+//!
+//! [`PrefixFactory`]: crate::prelude::PrefixFactory
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use crate::traits::PrefixFactory;
+use crate::{validation, TypeIdPrefix, ValidationError};
+
+/// Checks every input without allocating a `TypeIdPrefix` for it.
+///
+/// Inputs may be anything that can be viewed as bytes, such as `&str`, `String` or `&[u8]`.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::batch;
+///
+/// let column = vec!["user".to_string(), "_invalid".to_string()];
+/// let results = batch::check_many(&column);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn check_many<I>(inputs: I) -> Vec<Result<(), ValidationError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    inputs
+        .into_iter()
+        .map(|input| check_bytes_chunked(input.as_ref()))
+        .collect()
+}
+
+/// Validates every input, creating a `TypeIdPrefix` for each valid one.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::batch;
+/// use typeid_prefix::ValidationError;
+///
+/// let results = batch::validate_many(["user", ""]);
+/// assert_eq!(results[0].as_ref().unwrap().as_str(), "user");
+/// assert_eq!(results[1], Err(ValidationError::IsEmpty));
+/// ```
+pub fn validate_many<I>(inputs: I) -> Vec<Result<TypeIdPrefix, ValidationError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    inputs
+        .into_iter()
+        .map(|input| {
+            let input = input.as_ref();
+            check_bytes_chunked(input).map(|()| TypeIdPrefix::from_ascii_unchecked(input))
+        })
+        .collect()
+}
+
+/// Validates every input in parallel using [`PrefixFactory::try_create_prefix`].
+///
+/// Accepts any collection or iterator of strings, such as `Vec<String>`, `&[&str]` or
+/// `str::lines`. The inputs are collected into a `Vec` before being split across threads, so
+/// results are returned in the same order as the inputs.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::batch;
+/// use typeid_prefix::ValidationError;
+///
+/// let column = vec!["user".to_string(), "Invalid".to_string()];
+/// let results = batch::validate_batch(&column);
+/// assert_eq!(results[0].as_ref().unwrap().as_str(), "user");
+/// assert_eq!(results[1], Err(ValidationError::InvalidStartCharacter));
+///
+/// let results = batch::validate_batch("user\norder_item".lines());
+/// assert!(results.iter().all(Result::is_ok));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn validate_batch<I>(inputs: I) -> Vec<Result<TypeIdPrefix, ValidationError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+{
+    inputs
+        .into_iter()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|input| input.try_create_prefix())
+        .collect()
+}
+
+/// Sanitizes every input in parallel using [`PrefixFactory::create_prefix_sanitized`].
+///
+/// Accepts the same inputs as [`validate_batch`]. Inputs that sanitize to an empty string are
+/// reported as [`ValidationError::IsEmpty`]. Results are returned in the same order as the
+/// inputs.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::batch;
+/// use typeid_prefix::ValidationError;
+///
+/// let column = vec!["User Account", "123"];
+/// let results = batch::sanitize_batch(&column);
+/// assert_eq!(results[0].as_ref().unwrap().as_str(), "useraccount");
+/// assert_eq!(results[1], Err(ValidationError::IsEmpty));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn sanitize_batch<I>(inputs: I) -> Vec<Result<TypeIdPrefix, ValidationError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str> + Send,
+{
+    inputs
+        .into_iter()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|input| TypeIdPrefix::try_from(input.create_prefix_sanitized()))
+        .collect()
+}
+
+/// Validates `input` like `validation::check_bytes`, but scans eight bytes at a time.
+///
+/// Each chunk is loaded into a `u64` and classified with branch-free bit arithmetic, which
+/// compilers can turn into vector instructions on most targets.
+fn check_bytes_chunked(input: &[u8]) -> Result<(), ValidationError> {
+    if input.len() > validation::MAX_LENGTH {
+        return Err(ValidationError::ExceedsMaxLength);
+    }
+
+    let (Some(&first), Some(&last)) = (input.first(), input.last()) else {
+        return Err(ValidationError::IsEmpty);
+    };
+
+    let mut seen = 0;
+    let mut chunks = input.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        seen |= classify_word(u64::from_le_bytes(word));
+    }
+    for &byte in chunks.remainder() {
+        seen |= validation::CLASSES[byte as usize];
+    }
+
+    validation::finish(first, last, seen)
+}
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Classifies eight bytes at once, returning the OR of the classes that `validation::finish` inspects.
+const fn classify_word(word: u64) -> u8 {
+    if word & HIGH_BITS != 0 {
+        return validation::NON_ASCII;
+    }
+
+    // With the high bit of every byte clear, none of the additions below can carry into the
+    // neighbouring byte, so the high bit of each byte holds the result for that byte.
+    let at_least_a = word + ONES * (0x80 - b'a' as u64);
+    let above_z = word + ONES * (0x80 - (b'z' as u64 + 1));
+    let lowercase = at_least_a & !above_z;
+
+    let not_underscore_bits = word ^ (ONES * b'_' as u64);
+    let not_underscore = (not_underscore_bits + ONES * 0x7F) | not_underscore_bits;
+    let underscore = !not_underscore;
+
+    if (lowercase | underscore) & HIGH_BITS == HIGH_BITS {
+        0
+    } else {
+        validation::OTHER_ASCII
+    }
+}
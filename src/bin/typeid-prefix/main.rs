@@ -0,0 +1,189 @@
+//! Command-line tool for validating, sanitizing and explaining `TypeID` prefixes, for
+//! encoding, decoding and inspecting full `TypeID`s, and for linting prefix literals in
+//! Rust source files.
+//!
+//! Inputs are taken from the command line, or read from stdin line by line when no
+//! inputs are given (or when the only input is `-`). The process exits with status 1
+//! when any input is invalid.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use uuid::Uuid;
+
+use crate::report::{Explanation, LintReport, Report, Sanitization, TypeIdReport, Validation};
+
+mod report;
+mod typeid;
+
+/// Validate, sanitize and explain `TypeID` prefixes, generate, encode and decode `TypeID`s,
+/// and lint prefix literals in Rust source files.
+#[derive(Debug, Parser)]
+#[command(name = "typeid-prefix", version)]
+struct Cli {
+    /// Output format.
+    #[arg(long, short, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check whether each input is a valid prefix.
+    Validate(Inputs),
+    /// Sanitize each input into a valid prefix.
+    Sanitize(Inputs),
+    /// Show which prefix rules each input satisfies or violates.
+    Explain(Inputs),
+    /// Generate a new `TypeID` with the given prefix and a `UUIDv7` suffix.
+    New {
+        /// The prefix of the new `TypeID`. Pass `""` for a `TypeID` without a prefix.
+        prefix: String,
+    },
+    /// Decode each `TypeID` into its prefix, UUID and `UUIDv7` timestamp.
+    Decode(Inputs),
+    /// Encode a prefix and a UUID as a `TypeID`.
+    Encode {
+        /// The prefix of the `TypeID`. Pass `""` for a `TypeID` without a prefix.
+        prefix: String,
+        /// The UUID to encode as the suffix.
+        uuid: Uuid,
+    },
+    /// Find prefix literals in Rust source files that would fail validation or be changed
+    /// by sanitization.
+    Lint {
+        /// Rust files or directories to scan. Defaults to the current directory.
+        paths: Vec<PathBuf>,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct Inputs {
+    /// Inputs to process. Reads stdin line by line when omitted or `-`.
+    inputs: Vec<String>,
+}
+
+/// How reports are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text.
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("typeid-prefix: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs the command, returning whether every input was valid.
+fn run(cli: &Cli) -> io::Result<bool> {
+    match &cli.command {
+        Command::Validate(inputs) => process(inputs, cli.format, Validation::new),
+        Command::Sanitize(inputs) => process(inputs, cli.format, Sanitization::new),
+        Command::Explain(inputs) => process(inputs, cli.format, Explanation::new),
+        Command::New { prefix } => emit(&TypeIdReport::generate(prefix), cli.format),
+        Command::Decode(inputs) => process(inputs, cli.format, TypeIdReport::decode),
+        Command::Encode { prefix, uuid } => emit(&TypeIdReport::encode(prefix, *uuid), cli.format),
+        Command::Lint { paths } => lint(paths, cli.format),
+    }
+}
+
+fn lint(paths: &[PathBuf], format: Format) -> io::Result<bool> {
+    let mut files = Vec::new();
+    if paths.is_empty() {
+        collect_rust_files(Path::new("."), &mut files)?;
+    }
+    for path in paths {
+        collect_rust_files(path, &mut files)?;
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut clean = true;
+    for file in files {
+        // Report files that cannot be read as UTF-8 text, but keep scanning the others
+        let source = match fs::read_to_string(&file) {
+            Ok(source) => source,
+            Err(e) => {
+                clean = false;
+                eprintln!("typeid-prefix: {}: {e}", file.display());
+                continue;
+            }
+        };
+        for issue in typeid_prefix::lint::lint_source(&source) {
+            clean = false;
+            LintReport::new(&file, issue).write(&mut stdout, format)?;
+        }
+    }
+
+    stdout.flush()?;
+    Ok(clean)
+}
+
+/// Collects `.rs` files under `path`, skipping hidden directories and `target`.
+fn collect_rust_files(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::path);
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                collect_rust_files(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn emit(report: &impl Report, format: Format) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    report.write(&mut stdout, format)?;
+    stdout.flush()?;
+    Ok(report.is_valid())
+}
+
+fn process<R: Report>(inputs: &Inputs, format: Format, report: impl Fn(&str) -> R) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    let mut all_valid = true;
+    let mut report_one = |input: &str| -> io::Result<()> {
+        let report = report(input);
+        all_valid &= report.is_valid();
+        report.write(&mut stdout, format)
+    };
+
+    if inputs.inputs.is_empty() || inputs.inputs == ["-"] {
+        for line in io::stdin().lock().lines() {
+            report_one(&line?)?;
+        }
+    } else {
+        for input in &inputs.inputs {
+            report_one(input)?;
+        }
+    }
+
+    stdout.flush()?;
+    Ok(all_valid)
+}
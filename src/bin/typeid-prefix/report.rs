@@ -0,0 +1,324 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use serde::Serialize;
+use typeid_prefix::lint::LintIssue;
+use typeid_prefix::prelude::*;
+use uuid::Uuid;
+
+use crate::typeid::{format_rfc3339, TypeId, TypeIdError};
+use crate::Format;
+
+/// A result for a single input that can be written as text or JSON.
+pub trait Report: Serialize {
+    /// Returns whether the input is considered valid for the exit status.
+    fn is_valid(&self) -> bool;
+
+    /// Writes the human-readable form of the report.
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()>;
+
+    /// Writes the report in the requested format.
+    fn write(&self, out: &mut impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Text => self.write_text(out),
+            Format::Json => {
+                serde_json::to_writer(&mut *out, self)?;
+                writeln!(out)
+            }
+        }
+    }
+}
+
+/// A `ValidationError` with its variant name, as printed in reports.
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    variant: String,
+    message: String,
+}
+
+impl From<ValidationError> for ErrorReport {
+    fn from(error: ValidationError) -> Self {
+        Self {
+            variant: format!("{error:?}"),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The outcome of `validate` for one input.
+#[derive(Debug, Serialize)]
+pub struct Validation {
+    input: String,
+    valid: bool,
+    error: Option<ErrorReport>,
+}
+
+impl Validation {
+    pub fn new(input: &str) -> Self {
+        let error = TypeIdPrefix::try_from(input).err();
+        Self {
+            input: input.to_string(),
+            valid: error.is_none(),
+            error: error.map(ErrorReport::from),
+        }
+    }
+}
+
+impl Report for Validation {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        match &self.error {
+            None => writeln!(out, "{:?}: valid", self.input),
+            Some(error) => writeln!(out, "{:?}: invalid ({}): {}", self.input, error.variant, error.message),
+        }
+    }
+}
+
+/// The outcome of `sanitize` for one input.
+#[derive(Debug, Serialize)]
+pub struct Sanitization {
+    input: String,
+    sanitized: String,
+    valid: bool,
+    error: Option<ErrorReport>,
+}
+
+impl Sanitization {
+    pub fn new(input: &str) -> Self {
+        let sanitized = input.create_prefix_sanitized().to_string();
+        let error = TypeIdPrefix::try_from(sanitized.as_str()).err();
+        Self {
+            input: input.to_string(),
+            sanitized,
+            valid: error.is_none(),
+            error: error.map(ErrorReport::from),
+        }
+    }
+}
+
+impl Report for Sanitization {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        match &self.error {
+            None => writeln!(out, "{:?} -> {}", self.input, self.sanitized),
+            Some(error) => writeln!(out, "{:?} -> invalid ({}): {}", self.input, error.variant, error.message),
+        }
+    }
+}
+
+/// A single prefix rule and whether the input satisfies it.
+#[derive(Debug, Serialize)]
+pub struct RuleCheck {
+    rule: &'static str,
+    passed: bool,
+}
+
+/// The outcome of `explain` for one input.
+#[derive(Debug, Serialize)]
+pub struct Explanation {
+    input: String,
+    valid: bool,
+    error: Option<ErrorReport>,
+    rules: Vec<RuleCheck>,
+}
+
+impl Explanation {
+    pub fn new(input: &str) -> Self {
+        let first = input.chars().next();
+        let last = input.chars().last();
+        let rules = [
+            ("is not empty", !input.is_empty()),
+            ("is at most 63 bytes long", input.len() <= 63),
+            ("contains only ASCII characters", input.is_ascii()),
+            ("does not start with an underscore", first != Some('_')),
+            ("does not end with an underscore", last != Some('_')),
+            ("starts with a lowercase letter", first.is_some_and(|c| c.is_ascii_lowercase())),
+            ("ends with a lowercase letter", last.is_some_and(|c| c.is_ascii_lowercase())),
+            (
+                "contains only lowercase letters and underscores",
+                input.chars().all(|c| c.is_ascii_lowercase() || c == '_'),
+            ),
+        ]
+        .into_iter()
+        .map(|(rule, passed)| RuleCheck { rule, passed })
+        .collect();
+
+        let error = TypeIdPrefix::try_from(input).err();
+        Self {
+            input: input.to_string(),
+            valid: error.is_none(),
+            error: error.map(ErrorReport::from),
+            rules,
+        }
+    }
+}
+
+impl Report for Explanation {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        match &self.error {
+            None => writeln!(out, "{:?}: valid", self.input)?,
+            Some(error) => writeln!(out, "{:?}: invalid ({}): {}", self.input, error.variant, error.message)?,
+        }
+        for check in &self.rules {
+            let status = if check.passed { "ok" } else { "FAIL" };
+            writeln!(out, "  [{status:>4}] {}", check.rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `TypeIdError` with the half that failed, as printed in reports.
+#[derive(Debug, Serialize)]
+pub struct TypeIdErrorReport {
+    part: &'static str,
+    variant: String,
+    message: String,
+}
+
+impl From<TypeIdError> for TypeIdErrorReport {
+    fn from(error: TypeIdError) -> Self {
+        Self {
+            part: error.part(),
+            variant: error.variant(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The outcome of `new`, `decode` or `encode` for one `TypeID`.
+#[derive(Debug, Serialize)]
+pub struct TypeIdReport {
+    input: String,
+    valid: bool,
+    typeid: Option<String>,
+    prefix: Option<String>,
+    uuid: Option<String>,
+    timestamp: Option<String>,
+    error: Option<TypeIdErrorReport>,
+}
+
+impl TypeIdReport {
+    /// Reports a `TypeID` parsed from `input`.
+    pub fn decode(input: &str) -> Self {
+        Self::from_result(input, TypeId::parse(input))
+    }
+
+    /// Reports a new `TypeID` with the given prefix and a freshly generated `UUIDv7`.
+    pub fn generate(prefix: &str) -> Self {
+        Self::encode(prefix, Uuid::now_v7())
+    }
+
+    /// Reports the `TypeID` combining the given prefix and UUID.
+    ///
+    /// An empty prefix produces the spec's prefix-less form, which is just the suffix.
+    pub fn encode(prefix: &str, uuid: Uuid) -> Self {
+        let result = prefix
+            .parse::<OptionalPrefix>()
+            .map(|prefix| TypeId { prefix: prefix.into_prefix(), uuid })
+            .map_err(TypeIdError::Prefix);
+        Self::from_result(prefix, result)
+    }
+
+    fn from_result(input: &str, result: Result<TypeId, TypeIdError>) -> Self {
+        match result {
+            Ok(typeid) => Self {
+                input: input.to_string(),
+                valid: true,
+                typeid: Some(typeid.to_string()),
+                prefix: Some(typeid.prefix.as_ref().map(ToString::to_string).unwrap_or_default()),
+                uuid: Some(typeid.uuid.hyphenated().to_string()),
+                timestamp: typeid.unix_millis().map(format_rfc3339),
+                error: None,
+            },
+            Err(error) => Self {
+                input: input.to_string(),
+                valid: false,
+                typeid: None,
+                prefix: None,
+                uuid: None,
+                timestamp: None,
+                error: Some(error.into()),
+            },
+        }
+    }
+}
+
+impl Report for TypeIdReport {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        if let Some(error) = &self.error {
+            return writeln!(
+                out,
+                "{:?}: invalid {} ({}): {}",
+                self.input, error.part, error.variant, error.message
+            );
+        }
+        writeln!(out, "typeid:    {}", self.typeid.as_deref().unwrap_or_default())?;
+        writeln!(out, "prefix:    {}", self.prefix.as_deref().unwrap_or_default())?;
+        writeln!(out, "uuid:      {}", self.uuid.as_deref().unwrap_or_default())?;
+        if let Some(timestamp) = &self.timestamp {
+            writeln!(out, "timestamp: {timestamp}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A prefix literal found by `lint`.
+#[derive(Debug, Serialize)]
+pub struct LintReport {
+    path: String,
+    line: usize,
+    column: usize,
+    call: String,
+    literal: String,
+    error: Option<ErrorReport>,
+    sanitized: Option<String>,
+    malformed: Option<String>,
+}
+
+impl LintReport {
+    pub fn new(path: &Path, issue: LintIssue) -> Self {
+        Self {
+            path: path.display().to_string(),
+            line: issue.line,
+            column: issue.column,
+            call: issue.call.to_string(),
+            literal: issue.literal,
+            error: issue.error.map(ErrorReport::from),
+            sanitized: issue.sanitized,
+            malformed: issue.malformed.map(|malformed| malformed.to_string()),
+        }
+    }
+}
+
+impl Report for LintReport {
+    fn is_valid(&self) -> bool {
+        false
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> io::Result<()> {
+        if let Some(malformed) = &self.malformed {
+            let (path, line, column) = (&self.path, self.line, self.column);
+            return writeln!(out, "{path}:{line}:{column}: {}(\"{}\") {malformed}", self.call, self.literal);
+        }
+        write!(out, "{}:{}:{}: {}({:?})", self.path, self.line, self.column, self.call, self.literal)?;
+        match (&self.error, &self.sanitized) {
+            (Some(error), _) => writeln!(out, " would fail with {}: {}", error.variant, error.message),
+            (None, Some(sanitized)) => writeln!(out, " would be sanitized to {sanitized:?}"),
+            (None, None) => writeln!(out),
+        }
+    }
+}
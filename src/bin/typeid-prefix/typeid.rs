@@ -0,0 +1,213 @@
+use std::fmt;
+
+use typeid_prefix::prelude::*;
+use uuid::Uuid;
+
+/// The alphabet used by the `TypeID` suffix: Crockford's base32, lowercase.
+const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// The length of an encoded `TypeID` suffix.
+const SUFFIX_LENGTH: usize = 26;
+
+/// Errors that can occur while decoding the suffix half of a `TypeID`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuffixError {
+    /// The suffix is not exactly 26 characters long.
+    InvalidLength,
+    /// The suffix contains a character outside the lowercase base32 alphabet.
+    InvalidCharacter,
+    /// The first character is greater than `7`, so the suffix does not fit in 128 bits.
+    Overflow,
+}
+
+impl fmt::Display for SuffixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error_message = match self {
+            Self::InvalidLength => "Suffix must be exactly 26 characters",
+            Self::InvalidCharacter => "Suffix contains characters outside the lowercase base32 alphabet",
+            Self::Overflow => "Suffix must start with a character between 0 and 7",
+        };
+        write!(f, "{error_message}")
+    }
+}
+
+/// Identifies which half of a `TypeID` failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeIdError {
+    /// The prefix failed `TypeIdPrefix` validation.
+    Prefix(ValidationError),
+    /// The suffix is not a valid base32-encoded UUID.
+    Suffix(SuffixError),
+}
+
+impl TypeIdError {
+    /// Returns the name of the half that failed.
+    pub const fn part(self) -> &'static str {
+        match self {
+            Self::Prefix(_) => "prefix",
+            Self::Suffix(_) => "suffix",
+        }
+    }
+
+    /// Returns the name of the underlying error variant.
+    pub fn variant(self) -> String {
+        match self {
+            Self::Prefix(e) => format!("{e:?}"),
+            Self::Suffix(e) => format!("{e:?}"),
+        }
+    }
+}
+
+impl fmt::Display for TypeIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(e) => write!(f, "{e}"),
+            Self::Suffix(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A parsed `TypeID`: an optional prefix and a UUID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeId {
+    pub prefix: Option<TypeIdPrefix>,
+    pub uuid: Uuid,
+}
+
+impl TypeId {
+    /// Parses a `TypeID` in the `prefix_suffix` or bare `suffix` form.
+    pub fn parse(input: &str) -> Result<Self, TypeIdError> {
+        let (prefix, suffix) = match input.rsplit_once('_') {
+            // A separator with nothing before it is an empty prefix, which is invalid
+            Some((prefix, suffix)) => (Some(TypeIdPrefix::try_from(prefix).map_err(TypeIdError::Prefix)?), suffix),
+            None => (None, input),
+        };
+        let uuid = decode_suffix(suffix).map_err(TypeIdError::Suffix)?;
+        Ok(Self { prefix, uuid })
+    }
+
+    /// Returns the Unix timestamp in milliseconds embedded in a `UUIDv7`.
+    pub fn unix_millis(&self) -> Option<u64> {
+        (self.uuid.get_version_num() == 7).then(|| {
+            let (seconds, nanos) = self.uuid.get_timestamp().map_or((0, 0), |ts| ts.to_unix());
+            seconds * 1000 + u64::from(nanos / 1_000_000)
+        })
+    }
+}
+
+impl fmt::Display for TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = &self.prefix {
+            write!(f, "{prefix}_")?;
+        }
+        write!(f, "{}", encode_suffix(self.uuid))
+    }
+}
+
+/// Encodes a UUID as the 26-character `TypeID` suffix.
+pub fn encode_suffix(uuid: Uuid) -> String {
+    let value = uuid.as_u128();
+    (0..SUFFIX_LENGTH)
+        .map(|i| {
+            // The 128 bits are left-padded with two zero bits to fill 26 five-bit groups
+            let shift = 5 * (SUFFIX_LENGTH - 1 - i);
+            char::from(ALPHABET[((value >> shift) & 0x1F) as usize])
+        })
+        .collect()
+}
+
+/// Decodes a 26-character `TypeID` suffix into a UUID.
+pub fn decode_suffix(suffix: &str) -> Result<Uuid, SuffixError> {
+    if suffix.len() != SUFFIX_LENGTH {
+        return Err(SuffixError::InvalidLength);
+    }
+
+    let mut value: u128 = 0;
+    for (i, byte) in suffix.bytes().enumerate() {
+        let digit = ALPHABET
+            .iter()
+            .position(|&c| c == byte)
+            .ok_or(SuffixError::InvalidCharacter)?;
+        if i == 0 && digit > 7 {
+            return Err(SuffixError::Overflow);
+        }
+        value = (value << 5) | digit as u128;
+    }
+
+    Ok(Uuid::from_u128(value))
+}
+
+/// Formats Unix milliseconds as an RFC 3339 timestamp in UTC.
+pub fn format_rfc3339(unix_millis: u64) -> String {
+    let seconds = unix_millis / 1000;
+    let days = seconds / 86_400;
+    let time_of_day = seconds % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60,
+        unix_millis % 1000
+    )
+}
+
+/// Converts days since 1970-01-01 into a proleptic Gregorian `(year, month, day)`.
+const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of each cycle
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suffix_round_trip() {
+        let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+        let suffix = encode_suffix(uuid);
+        assert_eq!(suffix, "01h455vb4pex5vsknk084sn02q");
+        assert_eq!(decode_suffix(&suffix), Ok(uuid));
+    }
+
+    #[test]
+    fn test_suffix_bounds() {
+        assert_eq!(encode_suffix(Uuid::nil()), "00000000000000000000000000");
+        assert_eq!(encode_suffix(Uuid::max()), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+        assert_eq!(decode_suffix("8zzzzzzzzzzzzzzzzzzzzzzzzz"), Err(SuffixError::Overflow));
+        assert_eq!(decode_suffix("0000000000000000000000000"), Err(SuffixError::InvalidLength));
+        assert_eq!(decode_suffix("0000000000000000000000000u"), Err(SuffixError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_parse_reports_failing_half() {
+        assert_eq!(
+            TypeId::parse("User_01h455vb4pex5vsknk084sn02q").unwrap_err(),
+            TypeIdError::Prefix(ValidationError::InvalidStartCharacter)
+        );
+        assert_eq!(
+            TypeId::parse("_01h455vb4pex5vsknk084sn02q").unwrap_err(),
+            TypeIdError::Prefix(ValidationError::IsEmpty)
+        );
+        assert_eq!(
+            TypeId::parse("user_01h455vb4pex5vsknk084sn02Q").unwrap_err(),
+            TypeIdError::Suffix(SuffixError::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn test_format_rfc3339() {
+        assert_eq!(format_rfc3339(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(format_rfc3339(1_688_096_058_518), "2023-06-30T03:34:18.518Z");
+        assert_eq!(format_rfc3339(951_782_400_000), "2000-02-29T00:00:00.000Z");
+    }
+}
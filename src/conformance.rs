@@ -0,0 +1,86 @@
+//! Prefix test cases from the `TypeID` specification's conformance fixtures.
+//!
+//! The specification ships `valid.yml` and `invalid.yml` files listing full `TypeID`s that
+//! implementations must accept or reject. This module exposes the prefix half of every case
+//! that exercises the prefix rules, so crates wrapping [`TypeIdPrefix`](crate::TypeIdPrefix)
+//! or implementing their own validation can run the same suite.
+//!
+//! Cases keep the names used in the specification. For rejected prefixes, the
+//! [`ValidationError`] this crate reports is included as well.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::conformance;
+//! use typeid_prefix::TypeIdPrefixRef;
+//!
+//! let failures: Vec<_> = conformance::mismatches(|prefix| TypeIdPrefixRef::try_from(prefix).is_ok()).collect();
+//! assert!(failures.is_empty(), "failed conformance cases: {failures:?}");
+//! ```
+
+use crate::ValidationError;
+
+/// A prefix the specification requires implementations to accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidCase {
+    /// The name of the case in the specification's `valid.yml`.
+    pub name: &'static str,
+    /// The prefix of the case's `TypeID`.
+    pub prefix: &'static str,
+}
+
+/// A prefix the specification requires implementations to reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCase {
+    /// The name of the case in the specification's `invalid.yml`.
+    pub name: &'static str,
+    /// The text before the last `_` separator of the case's `TypeID`.
+    pub prefix: &'static str,
+    /// The error this crate reports for the prefix.
+    pub error: ValidationError,
+}
+
+/// The specification version the cases were taken from.
+pub const SPEC_VERSION: &str = "0.3.0";
+
+/// Prefixes from `valid.yml`. Cases without a prefix are omitted.
+pub const VALID: &[ValidCase] = &[
+    ValidCase { name: "valid-alphabet", prefix: "prefix" },
+    ValidCase { name: "valid-uuidv7", prefix: "prefix" },
+    ValidCase { name: "prefix-underscore", prefix: "pre_fix" },
+];
+
+/// Prefixes from `invalid.yml`. Cases that only exercise the suffix are omitted.
+pub const INVALID: &[InvalidCase] = &[
+    InvalidCase { name: "prefix-uppercase", prefix: "PREFIX", error: ValidationError::InvalidStartCharacter },
+    InvalidCase { name: "prefix-numeric", prefix: "12345", error: ValidationError::InvalidStartCharacter },
+    InvalidCase { name: "prefix-period", prefix: "pre.fix", error: ValidationError::ContainsInvalidCharacters },
+    InvalidCase { name: "prefix-non-ascii", prefix: "préfix", error: ValidationError::ContainsInvalidCharacters },
+    InvalidCase { name: "prefix-spaces", prefix: "  prefix", error: ValidationError::InvalidStartCharacter },
+    InvalidCase {
+        name: "prefix-64-chars",
+        prefix: "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijkl",
+        error: ValidationError::ExceedsMaxLength,
+    },
+    InvalidCase { name: "separator-empty-prefix", prefix: "", error: ValidationError::IsEmpty },
+    InvalidCase { name: "separator-empty", prefix: "", error: ValidationError::IsEmpty },
+    InvalidCase { name: "prefix-underscore-start", prefix: "_prefix", error: ValidationError::StartsWithUnderscore },
+    InvalidCase { name: "prefix-underscore-end", prefix: "prefix_", error: ValidationError::EndsWithUnderscore },
+    InvalidCase { name: "prefix-empty", prefix: "", error: ValidationError::IsEmpty },
+];
+
+/// Runs every case through `accepts` and yields the names of the cases it gets wrong.
+///
+/// `accepts` should return `true` if the prefix is valid. A conforming implementation
+/// yields nothing.
+pub fn mismatches<F>(mut accepts: F) -> impl Iterator<Item = &'static str>
+where
+    F: FnMut(&str) -> bool,
+{
+    let valid = VALID.iter().map(|case| (case.name, case.prefix, true));
+    let invalid = INVALID.iter().map(|case| (case.name, case.prefix, false));
+    valid
+        .chain(invalid)
+        .filter(move |&(_, prefix, expected)| accepts(prefix) != expected)
+        .map(|(name, _, _)| name)
+}
@@ -1,10 +1,10 @@
-use std::fmt;
+use core::fmt;
 
 /// Represents errors that can occur during validation of `TypeID` prefixes.
 ///
 /// This enum encapsulates various error conditions that may arise when validating
 /// a `TypeID` prefix according to the `TypeID` specification.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ValidationError {
     /// The input exceeds the maximum allowed length of 63 characters.
     ExceedsMaxLength,
@@ -78,6 +78,7 @@ impl fmt::Display for ValidationError {
 
 /// Implements the standard Error trait for `ValidationError`.
 ///
-/// This allows `ValidationError` to be used with the `std::error::Error` trait,
-/// enabling better interoperability with error handling mechanisms in Rust.
-impl std::error::Error for ValidationError {}
\ No newline at end of file
+/// This allows `ValidationError` to be used with the `core::error::Error` trait (re-exported
+/// as `std::error::Error`), enabling better interoperability with error handling mechanisms
+/// in Rust, including in `no_std` environments.
+impl core::error::Error for ValidationError {}
\ No newline at end of file
@@ -0,0 +1,242 @@
+//! Interned `TypeID` prefixes.
+//!
+//! An [`Interner`] stores each distinct [`TypeIdPrefix`] once and hands out [`InternedPrefix`]
+//! handles to it. Handles are `Copy`, and equality and hashing compare the address of the
+//! stored prefix instead of its characters.
+//!
+//! All prefixes are interned in the process-wide table returned by [`Interner::global`], which
+//! also backs the `From` and `TryFrom` conversions. Interned prefixes are never freed, so
+//! interning is meant for a bounded set of prefixes, such as the entity types of a service.
+//!
+//! There is no way to create a separate, user-provided table. Handles point to `'static`
+//! storage, so the entries of a table that could be dropped would never be freed. Code that
+//! needs prefixes scoped to a request or a task can use [`SharedTypeIdPrefix`] instead.
+//!
+//! [`SharedTypeIdPrefix`]: crate::SharedTypeIdPrefix
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::intern::InternedPrefix;
+//! use typeid_prefix::prelude::*;
+//!
+//! let user = InternedPrefix::try_from("user").unwrap();
+//! let copy = user;
+//! assert_eq!(user, copy);
+//! assert_eq!(InternedPrefix::try_from("user").unwrap(), user);
+//!
+//! let owned: TypeIdPrefix = user.into();
+//! assert_eq!(owned.as_str(), "user");
+//! assert_eq!(InternedPrefix::from(owned), user);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use crate::{TypeIdPrefix, ValidationError};
+
+static GLOBAL: LazyLock<Interner> = LazyLock::new(Interner::new);
+
+/// The process-wide table that stores each distinct `TypeIdPrefix` once.
+///
+/// Interning is thread-safe. The only instance is returned by [`Interner::global`]; stored
+/// prefixes live for the rest of the process, so a table that could be dropped would leak them.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::intern::Interner;
+///
+/// let interner = Interner::global();
+/// let first = interner.try_intern("order_item").unwrap();
+/// let second = interner.try_intern("order_item").unwrap();
+/// assert_eq!(first, second);
+/// assert_eq!(interner.get("order_item"), Some(first));
+/// ```
+#[derive(Debug)]
+pub struct Interner {
+    table: Mutex<HashMap<&'static str, InternedPrefix>>,
+}
+
+impl Interner {
+    fn new() -> Self {
+        Self {
+            table: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the process-wide interner used by the conversions on [`InternedPrefix`].
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// Interns `prefix`, storing a copy of it if this interner has not seen it before.
+    ///
+    /// The copy is never freed.
+    #[must_use]
+    pub fn intern(&self, prefix: &TypeIdPrefix) -> InternedPrefix {
+        let mut table = self.table.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&interned) = table.get(prefix.as_str()) {
+            return interned;
+        }
+        let stored: &'static TypeIdPrefix = Box::leak(Box::new(prefix.clone()));
+        let interned = InternedPrefix(stored);
+        table.insert(stored.as_str(), interned);
+        interned
+    }
+
+    /// Validates `input` and interns it.
+    ///
+    /// Input that has already been interned is not validated or copied again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid `TypeID` prefix.
+    pub fn try_intern(&self, input: &str) -> Result<InternedPrefix, ValidationError> {
+        if let Some(interned) = self.get(input) {
+            return Ok(interned);
+        }
+        Ok(self.intern(&TypeIdPrefix::validate(input)?))
+    }
+
+    /// Returns the handle for `input` if it has already been interned.
+    #[must_use]
+    pub fn get(&self, input: &str) -> Option<InternedPrefix> {
+        let table = self.table.lock().unwrap_or_else(PoisonError::into_inner);
+        table.get(input).copied()
+    }
+
+    /// Returns the number of distinct prefixes interned so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.table.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Returns `true` if nothing has been interned yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A `Copy` handle to a `TypeIdPrefix` stored in the global [`Interner`].
+///
+/// Equality and hashing use the address of the stored prefix, so they take constant time
+/// regardless of the prefix length.
+#[derive(Clone, Copy)]
+pub struct InternedPrefix(&'static TypeIdPrefix);
+
+impl InternedPrefix {
+    /// Returns a string slice of the interned prefix.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        self.0.as_str()
+    }
+
+    /// Returns the stored `TypeIdPrefix`.
+    #[must_use]
+    pub const fn as_prefix(self) -> &'static TypeIdPrefix {
+        self.0
+    }
+}
+
+impl PartialEq for InternedPrefix {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for InternedPrefix {}
+
+impl Hash for InternedPrefix {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.0, state);
+    }
+}
+
+impl PartialEq<str> for InternedPrefix {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for InternedPrefix {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<TypeIdPrefix> for InternedPrefix {
+    fn eq(&self, other: &TypeIdPrefix) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<InternedPrefix> for TypeIdPrefix {
+    fn eq(&self, other: &InternedPrefix) -> bool {
+        self == other.0
+    }
+}
+
+impl Deref for InternedPrefix {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for InternedPrefix {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<TypeIdPrefix> for InternedPrefix {
+    /// Interns `prefix` in the global interner.
+    fn from(prefix: TypeIdPrefix) -> Self {
+        Interner::global().intern(&prefix)
+    }
+}
+
+impl From<&TypeIdPrefix> for InternedPrefix {
+    /// Interns `prefix` in the global interner.
+    fn from(prefix: &TypeIdPrefix) -> Self {
+        Interner::global().intern(prefix)
+    }
+}
+
+impl From<InternedPrefix> for TypeIdPrefix {
+    fn from(prefix: InternedPrefix) -> Self {
+        prefix.0.clone()
+    }
+}
+
+impl TryFrom<&str> for InternedPrefix {
+    type Error = ValidationError;
+
+    /// Validates `input` and interns it in the global interner.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid `TypeID` prefix.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Interner::global().try_intern(input)
+    }
+}
+
+impl fmt::Debug for InternedPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InternedPrefix").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for InternedPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
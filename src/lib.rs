@@ -1,10 +1,13 @@
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
+// The crate docs link to alloc-only types, which do not exist in builds without `alloc`
+#![cfg_attr(not(feature = "alloc"), allow(rustdoc::broken_intra_doc_links))]
 #![warn(clippy::pedantic)]
 #![warn(clippy::nursery)]
 #![allow(clippy::module_name_repetitions)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! # `TypeID` Prefix
 //!
 //! This crate provides a type-safe implementation of the `TypePrefix` section of the
@@ -13,7 +16,7 @@
 //! The main type provided by this crate is [`TypeIdPrefix`], which represents a valid
 //! `TypeID` prefix. This type ensures that all instances conform to the `TypeID` specification:
 //!
-//! - Maximum length of 63 characters
+//! - Length of 1 to 63 characters
 //! - Contains only lowercase ASCII letters and underscores
 //! - Does not start or end with an underscore
 //! - Starts and ends with a lowercase letter
@@ -23,12 +26,17 @@
 //! - **Type-safe**: Ensures that `TypeID` prefixes conform to the specification.
 //! - **Validation**: Provides robust validation for `TypeID` prefixes.
 //! - **Sanitization**: Offers methods to clean and sanitize input strings into valid `TypeID` prefixes.
+//!   Sanitization returns an [`OptionalPrefix`], which is empty when nothing can be salvaged.
 //! - **Zero-cost abstractions**: Designed to have minimal runtime overhead.
+//! - **Cheap sharing**: [`SharedTypeIdPrefix`] stores a prefix in an `Arc<str>` for passing it across tasks.
 //! - **Optional tracing**: Integrates with the `tracing` crate for logging (optional feature).
+//! - **GraphQL support**: Provides an `async-graphql` scalar for `TypeIdPrefix` (optional feature).
 //!
 //! ## Usage
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
 //! use typeid_prefix::prelude::*;
 //! use std::convert::TryFrom;
 //!
@@ -43,24 +51,78 @@
 //! // Sanitize an invalid string
 //! let sanitized = "Invalid_Prefix123".create_prefix_sanitized();
 //! assert_eq!(sanitized.as_str(), "invalid_prefix");
+//!
+//! // Validate a slice of a larger buffer without allocating
+//! let borrowed = TypeIdPrefixRef::try_from(&"user_01h455vb4pex5vsknk084sn02q"[..4]).unwrap();
+//! assert_eq!(borrowed, "user");
+//! # }
 //! ```
 //!
 //! ## Optional Tracing
 //!
 //! When the `instrument` feature is enabled, the crate will log validation errors
 //! using the `tracing` crate.
+//!
+//! ## `no_std` Support
+//!
+//! The crate is `no_std` compatible. The default `std` feature can be disabled:
+//!
+//! - With the `alloc` feature, [`TypeIdPrefix`], [`SharedTypeIdPrefix`], sanitization and the
+//!   `batch` module remain available on top of the `alloc` crate.
+//! - Without any features, [`TypeIdPrefixRef`] and [`ValidationError`] can still be used to
+//!   validate prefixes without an allocator.
+//!
+//! [`ValidationError`] implements `core::error::Error` in every configuration. The `instrument`,
+//! `arbitrary`, `async-graphql`, `rayon`, `proptest`, `quickcheck` and `intern` features require `std`.
+//!
+//! ## GraphQL
+//!
+//! When the `async-graphql` feature is enabled, [`TypeIdPrefix`] can be used as a GraphQL
+//! scalar. Input values are validated and any [`ValidationError`] is reported as an input error.
 
 
-#[cfg(feature = "instrument")]
-use tracing;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "alloc")]
+pub use optional_prefix::OptionalPrefix;
+#[cfg(feature = "alloc")]
+pub use shared_type_id_prefix::SharedTypeIdPrefix;
+#[cfg(feature = "alloc")]
 pub use type_id_prefix::TypeIdPrefix;
+pub use spec_version::{PrefixRules, SpecVersion};
+pub use type_id_prefix_ref::TypeIdPrefixRef;
 
 pub use crate::error::ValidationError;
 
+#[cfg(feature = "alloc")]
+pub mod analysis;
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod conformance;
 mod error;
+#[cfg(feature = "intern")]
+pub mod intern;
+#[cfg(feature = "lint")]
+pub mod lint;
+#[cfg(feature = "alloc")]
+mod optional_prefix;
+#[cfg(feature = "alloc")]
+pub mod policy;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_modes;
+#[cfg(feature = "alloc")]
+mod shared_type_id_prefix;
+mod spec_version;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 mod traits;
+#[cfg(feature = "alloc")]
 mod type_id_prefix;
+mod type_id_prefix_ref;
+#[cfg(feature = "std")]
+mod type_name;
+mod validation;
 
 pub mod prelude {
     //! A prelude for the `TypeID` prefix crate.
@@ -72,11 +134,15 @@ pub mod prelude {
     //! ```
     //! use typeid_prefix::prelude::*;
     //! ```
-    pub use crate::{TypeIdPrefix, ValidationError};
-    pub use crate::traits::{PrefixFactory, Validate};
+    #[cfg(feature = "alloc")]
+    pub use crate::{OptionalPrefix, SharedTypeIdPrefix, TypeIdPrefix};
+    pub use crate::{SpecVersion, TypeIdPrefixRef, ValidationError};
+    #[cfg(feature = "alloc")]
+    pub use crate::traits::PrefixFactory;
+    pub use crate::traits::Validate;
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::convert::TryFrom;
 
@@ -84,6 +150,39 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_spec_versions() {
+        assert_eq!(SpecVersion::default(), SpecVersion::LATEST);
+        assert!(SpecVersion::V0_3.rules().allows_underscores);
+
+        for input in ["user", "a", "abcdefghijklmnopqrstuvwxyz"] {
+            assert!(TypeIdPrefix::try_from_spec(input, SpecVersion::V0_2).is_ok());
+            assert!(TypeIdPrefixRef::try_from_spec(input, SpecVersion::V0_2).is_ok());
+        }
+        assert_eq!(
+            TypeIdPrefix::try_from_spec("user_account", SpecVersion::V0_2),
+            Err(ValidationError::ContainsInvalidCharacters)
+        );
+        assert_eq!(
+            TypeIdPrefix::try_from_spec("_user", SpecVersion::V0_2),
+            Err(ValidationError::StartsWithUnderscore)
+        );
+        assert_eq!(
+            TypeIdPrefix::try_from_spec("user_account", SpecVersion::V0_3),
+            TypeIdPrefix::try_from("user_account")
+        );
+    }
+
+    #[test]
+    fn test_spec_version_sanitize() {
+        assert_eq!("_user__account_".create_prefix_sanitized_with(SpecVersion::V0_2).as_str(), "useraccount");
+        assert_eq!(
+            "_user__account_".create_prefix_sanitized_with(SpecVersion::V0_3),
+            "_user__account_".create_prefix_sanitized()
+        );
+        assert_eq!("user".try_create_prefix_with(SpecVersion::V0_2).unwrap().as_str(), "user");
+    }
+
     #[test]
     fn test_type_id_spaces_sanitize() {
         assert_eq!(
@@ -239,6 +338,166 @@ mod tests {
         assert_eq!("invalid1".create_prefix_sanitized().as_str(), "invalid");
     }
 
+    #[test]
+    fn test_typeid_prefix_ref_borrows_slice() {
+        let buffer = "user_01h455vb4pex5vsknk084sn02q";
+        let prefix = TypeIdPrefixRef::try_from(&buffer[..4]).unwrap();
+        assert_eq!(prefix.as_str(), "user");
+        assert_eq!(prefix.as_ptr(), buffer.as_ptr());
+    }
+
+    #[test]
+    fn test_typeid_prefix_ref_matches_owned_validation() {
+        for input in ["", "_invalid", "invalid_", "Invalid", "invalid1", "in valid", "🌀"] {
+            assert_eq!(
+                TypeIdPrefixRef::try_from(input).unwrap_err(),
+                TypeIdPrefix::try_from(input).unwrap_err()
+            );
+        }
+        assert_eq!(
+            TypeIdPrefixRef::try_from("a".repeat(64).as_str()).unwrap_err(),
+            ValidationError::ExceedsMaxLength
+        );
+    }
+
+    #[test]
+    fn test_typeid_prefix_ref_owned_conversion() {
+        let owned = TypeIdPrefix::try_from("valid_prefix").unwrap();
+        let borrowed = owned.as_prefix_ref();
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, borrowed);
+        assert_eq!(TypeIdPrefix::from(borrowed), owned);
+        assert_eq!(borrowed.to_string(), "valid_prefix");
+        assert_eq!(&*borrowed, "valid_prefix");
+    }
+
+    #[test]
+    fn test_shared_prefix_matches_owned() {
+        for input in ["", "a", "user_account", "_invalid", "invalid_", "Invalid", "🌀", "a".repeat(64).as_str()] {
+            let shared = SharedTypeIdPrefix::try_from(input);
+            let owned = TypeIdPrefix::try_from(input);
+            assert_eq!(shared.as_ref().map(SharedTypeIdPrefix::as_str), owned.as_ref().map(TypeIdPrefix::as_str));
+            assert_eq!(SharedTypeIdPrefix::try_from(input.to_string()), shared);
+        }
+    }
+
+    #[test]
+    fn test_shared_prefix_conversions() {
+        let owned = TypeIdPrefix::try_from("valid_prefix").unwrap();
+        let shared = SharedTypeIdPrefix::from(&owned);
+        let clone = shared.clone();
+        assert!(SharedTypeIdPrefix::ptr_eq(&shared, &clone));
+        assert_eq!(shared, owned);
+        assert_eq!(owned, shared);
+        assert_eq!(shared, owned.as_prefix_ref());
+        assert_eq!(TypeIdPrefix::from(clone), owned);
+        assert_eq!(shared.to_string(), "valid_prefix");
+        assert_eq!(&*shared, "valid_prefix");
+        assert_eq!(shared.as_prefix_ref(), "valid_prefix");
+    }
+
+    #[test]
+    fn test_typeid_prefix_from_bytes() {
+        assert_eq!(TypeIdPrefix::try_from_bytes(b"valid_string").unwrap().as_str(), "valid_string");
+        assert_eq!(TypeIdPrefix::try_from(b"valid_string".to_vec()).unwrap(), "valid_string");
+        assert_eq!(TypeIdPrefix::try_from(&b""[..]).unwrap_err(), ValidationError::IsEmpty);
+        assert_eq!(
+            TypeIdPrefix::try_from(&[b'a', 0xFF, b'z'][..]).unwrap_err(),
+            ValidationError::ContainsInvalidCharacters
+        );
+    }
+
+    #[test]
+    fn test_typeid_prefix_from_bytes_matches_str() {
+        for input in ["_invalid", "invalid_", "Invalid", "invalid1", "in valid", "🌀", "a".repeat(64).as_str()] {
+            assert_eq!(
+                TypeIdPrefix::try_from_bytes(input.as_bytes()).unwrap_err(),
+                TypeIdPrefix::try_from(input).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_classifies_every_byte_in_every_position() {
+        for position in 0..16 {
+            for byte in 0..=u8::MAX {
+                let mut input = b"abcdefgh_ijklmno".to_vec();
+                input[position] = byte;
+                assert_eq!(
+                    batch::check_many([&input])[0],
+                    TypeIdPrefix::try_from_bytes(&input).map(|_| ()),
+                    "byte {byte:#04x} at position {position}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_valid_and_check_match_try_from() {
+        const _: () = assert!(TypeIdPrefix::is_valid("user"));
+        for input in ["", "a", "user_account", "_invalid", "invalid_", "Invalid", "in valid", "🌀", "a".repeat(64).as_str()] {
+            let expected = TypeIdPrefix::try_from(input).map(|_| ());
+            assert_eq!(TypeIdPrefix::check(input), expected);
+            assert_eq!(TypeIdPrefix::is_valid(input), expected.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_optional_prefix() {
+        let empty = "123".create_prefix_sanitized();
+        assert_eq!(empty, OptionalPrefix::none());
+        assert_eq!(empty.as_str(), "");
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(TypeIdPrefix::try_from(empty), Err(ValidationError::IsEmpty));
+
+        let prefix = TypeIdPrefix::try_from("user").unwrap();
+        let sanitized = "User".create_prefix_sanitized();
+        assert_eq!(sanitized, prefix);
+        assert_eq!(sanitized.prefix(), Some(&prefix));
+        assert_eq!(OptionalPrefix::from(prefix.clone()), sanitized);
+        assert_eq!(TypeIdPrefix::try_from(sanitized), Ok(prefix));
+
+        assert_eq!("".parse::<OptionalPrefix>(), Ok(OptionalPrefix::none()));
+        assert_eq!("_user".parse::<OptionalPrefix>(), Err(ValidationError::StartsWithUnderscore));
+    }
+
+    #[test]
+    fn test_prefix_of_type_name() {
+        struct User;
+        #[allow(clippy::upper_case_acronyms)]
+        struct HTTPRequest;
+        struct ApiV2Key;
+        struct Page<T>(T);
+
+        assert_eq!(TypeIdPrefix::of::<User>().unwrap().as_str(), "user");
+        assert_eq!(TypeIdPrefix::of::<HTTPRequest>().unwrap().as_str(), "http_request");
+        assert_eq!(TypeIdPrefix::of::<Page<User>>().unwrap().as_str(), "page");
+        assert_eq!(TypeIdPrefix::of::<ApiV2Key>(), Err(ValidationError::ContainsInvalidCharacters));
+        assert_eq!(TypeIdPrefix::of::<str>().unwrap().as_str(), "str");
+        assert!(std::ptr::eq(TypeIdPrefix::of::<User>().unwrap(), TypeIdPrefix::of::<User>().unwrap()));
+    }
+
+    #[test]
+    fn test_canonicalize_keeps_valid_input() {
+        for input in ["a", "user", "user_account", "a__b", "a".repeat(63).as_str()] {
+            assert!(TypeIdPrefix::is_canonical(input));
+            assert_eq!(TypeIdPrefix::canonicalize(input).as_str(), input);
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_is_idempotent() {
+        for input in ["", "_", "User Account", "_user_", "123_456", "🌀user", "A".repeat(70).as_str()] {
+            let canonical = TypeIdPrefix::canonicalize(input);
+            assert!(TypeIdPrefix::is_canonical(canonical.as_str()));
+            assert_eq!(TypeIdPrefix::canonicalize(canonical.as_str()), canonical);
+            assert_eq!(canonical, input.create_prefix_sanitized());
+        }
+        assert!(!TypeIdPrefix::is_canonical("User"));
+        assert!(!TypeIdPrefix::is_canonical("user_"));
+        assert!(TypeIdPrefix::is_canonical(""));
+    }
+
     #[test]
     fn test_clean_inner_only_underscores() {
         // This would have panicked before the fix
@@ -0,0 +1,451 @@
+//! Finds string literals in Rust source code that are not valid `TypeID` prefixes.
+//!
+//! Calls such as `TypeIdPrefix::try_from("User").unwrap()` only fail at runtime. The
+//! [`lint_source`] function scans Rust source text for prefix constructors applied to string
+//! literals and reports, without compiling the code:
+//!
+//! - literals passed to `TypeIdPrefix::try_from`, `TypeIdPrefix::from_str` or
+//!   `try_create_prefix` that would return a [`ValidationError`], and
+//! - literals passed to `create_prefix_sanitized` whose sanitized form differs from the literal.
+//!
+//! Literals passed to these constructors that contain an invalid escape or are never closed are
+//! reported as [`MalformedLiteral`]s, since their value cannot be checked.
+//!
+//! The scanner understands comments, character literals, lifetimes, escapes and raw strings,
+//! but it does not resolve imports or constants; only literals written at the call site are
+//! checked.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::lint::{lint_source, LintCall};
+//! use typeid_prefix::ValidationError;
+//!
+//! let source = r#"
+//!     let ok = TypeIdPrefix::try_from("user").unwrap();
+//!     let bad = TypeIdPrefix::try_from("User").unwrap();
+//!     let cleaned = "Order Item".create_prefix_sanitized();
+//! "#;
+//!
+//! let issues = lint_source(source);
+//! assert_eq!(issues.len(), 2);
+//! assert_eq!(issues[0].line, 3);
+//! assert_eq!(issues[0].call, LintCall::TryFrom);
+//! assert_eq!(issues[0].error, Some(ValidationError::InvalidStartCharacter));
+//! assert_eq!(issues[1].sanitized.as_deref(), Some("orderitem"));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::traits::PrefixFactory;
+use crate::{TypeIdPrefix, ValidationError};
+
+/// The prefix constructor a string literal was passed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintCall {
+    /// `TypeIdPrefix::try_from("...")`
+    TryFrom,
+    /// `TypeIdPrefix::from_str("...")`
+    FromStr,
+    /// `"...".try_create_prefix()`
+    TryCreatePrefix,
+    /// `"...".create_prefix_sanitized()`
+    CreatePrefixSanitized,
+}
+
+impl fmt::Display for LintCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let call = match self {
+            Self::TryFrom => "TypeIdPrefix::try_from",
+            Self::FromStr => "TypeIdPrefix::from_str",
+            Self::TryCreatePrefix => "try_create_prefix",
+            Self::CreatePrefixSanitized => "create_prefix_sanitized",
+        };
+        write!(f, "{call}")
+    }
+}
+
+/// Why the value of a string literal could not be determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MalformedLiteral {
+    /// The literal contains an escape sequence that Rust does not accept.
+    InvalidEscape,
+    /// The literal is not closed before the end of the source.
+    Unterminated,
+}
+
+impl fmt::Display for MalformedLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::InvalidEscape => "contains an invalid escape",
+            Self::Unterminated => "is not terminated",
+        };
+        write!(f, "{reason}")
+    }
+}
+
+/// A string literal that would not produce the prefix it spells out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// The 1-based line of the literal's opening quote.
+    pub line: usize,
+    /// The 1-based column, in characters, of the literal's opening quote.
+    pub column: usize,
+    /// The constructor the literal is passed to.
+    pub call: LintCall,
+    /// The value of the literal, with escapes resolved.
+    ///
+    /// For a malformed literal this is its source text instead, up to the end of the line if
+    /// it is not terminated.
+    pub literal: String,
+    /// The error the constructor would return, for validating constructors.
+    pub error: Option<ValidationError>,
+    /// The sanitized prefix, for `create_prefix_sanitized` when it differs from the literal.
+    pub sanitized: Option<String>,
+    /// Why the literal's value could not be checked, if it is malformed.
+    pub malformed: Option<MalformedLiteral>,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(malformed) = self.malformed {
+            return write!(f, "{}:{}: {}(\"{}\") {malformed}", self.line, self.column, self.call, self.literal);
+        }
+        write!(f, "{}:{}: {}({:?})", self.line, self.column, self.call, self.literal)?;
+        match (&self.error, &self.sanitized) {
+            (Some(error), _) => write!(f, " would fail with {error:?}: {error}"),
+            (None, Some(sanitized)) => write!(f, " would be sanitized to {sanitized:?}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Scans Rust source code for prefix constructors called on invalid string literals.
+///
+/// Issues are returned in the order they appear in the source.
+#[must_use]
+pub fn lint_source(source: &str) -> Vec<LintIssue> {
+    Scanner::new(source)
+        .filter_map(|literal| check_literal(source, &literal))
+        .collect()
+}
+
+fn check_literal(source: &str, literal: &StrLiteral) -> Option<LintIssue> {
+    let before = &source[..literal.start];
+    let call = match literal.value {
+        // Nothing follows an unterminated literal, so only a constructor before it can be found
+        Err(MalformedLiteral::Unterminated) => constructor_call(before)?,
+        _ => call_site(before, &source[literal.end..])?,
+    };
+    let value = match &literal.value {
+        Ok(value) => value.as_str(),
+        Err(malformed) => {
+            return Some(LintIssue {
+                line: literal.line,
+                column: literal.column,
+                call,
+                literal: literal.source_text(source).into(),
+                error: None,
+                sanitized: None,
+                malformed: Some(*malformed),
+            })
+        }
+    };
+    let (error, sanitized) = match call {
+        LintCall::CreatePrefixSanitized => {
+            let sanitized = value.create_prefix_sanitized();
+            (None, (sanitized.as_str() != value).then(|| sanitized.as_str().into()))
+        }
+        _ => (TypeIdPrefix::validate(value).err(), None),
+    };
+    (error.is_some() || sanitized.is_some()).then(|| LintIssue {
+        line: literal.line,
+        column: literal.column,
+        call,
+        literal: value.into(),
+        error,
+        sanitized,
+        malformed: None,
+    })
+}
+
+/// Determines which constructor, if any, the literal between `before` and `after` is passed to.
+fn call_site(before: &str, after: &str) -> Option<LintCall> {
+    let after = after.trim_start();
+
+    // The literal must be the only argument, optionally followed by a trailing comma
+    let closing = after.strip_prefix(',').map_or(after, str::trim_start);
+    if closing.starts_with(')') {
+        if let Some(call) = constructor_call(before) {
+            return Some(call);
+        }
+    }
+
+    let method = after.strip_prefix('.')?.trim_start();
+    let (name, rest) = method.split_at(method.find(|c: char| !is_ident_char(c)).unwrap_or(method.len()));
+    if !rest.trim_start().starts_with('(') {
+        return None;
+    }
+    match name {
+        "create_prefix_sanitized" => Some(LintCall::CreatePrefixSanitized),
+        "try_create_prefix" => Some(LintCall::TryCreatePrefix),
+        _ => None,
+    }
+}
+
+/// Determines which path constructor, if any, is opened by the call at the end of `before`.
+fn constructor_call(before: &str) -> Option<LintCall> {
+    let path = before.trim_end().strip_suffix('(')?.trim_end();
+    if ends_with_path(path, "TypeIdPrefix::try_from") {
+        Some(LintCall::TryFrom)
+    } else if ends_with_path(path, "TypeIdPrefix::from_str") {
+        Some(LintCall::FromStr)
+    } else {
+        None
+    }
+}
+
+fn ends_with_path(text: &str, path: &str) -> bool {
+    text.strip_suffix(path)
+        .is_some_and(|rest| !rest.ends_with(is_ident_char))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// A string literal found in the source.
+struct StrLiteral {
+    /// Byte offset of the opening quote (or `r` prefix).
+    start: usize,
+    /// Byte offset just past the closing quote (and any `#`s).
+    end: usize,
+    line: usize,
+    column: usize,
+    /// The unescaped value, or why it could not be read.
+    value: Result<String, MalformedLiteral>,
+}
+
+impl StrLiteral {
+    /// Returns the text between the quotes, cut at the end of the line if it is unterminated.
+    fn source_text<'a>(&self, source: &'a str) -> &'a str {
+        let text = &source[self.start..self.end];
+        let body = &text[text.find('"').map_or(0, |quote| quote + 1)..];
+        match self.value {
+            Err(MalformedLiteral::Unterminated) => body.lines().next().unwrap_or_default(),
+            _ => body.strip_suffix('"').unwrap_or(body),
+        }
+    }
+}
+
+/// Iterates over the string literals in Rust source code, skipping comments and byte strings.
+struct Scanner<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Scanner<'a> {
+    const fn new(source: &'a str) -> Self {
+        Self { source, pos: 0, line: 1, column: 1 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn bump_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.bump();
+        }
+    }
+
+    fn skip_line_comment(&mut self) {
+        while self.peek().is_some_and(|c| c != '\n') {
+            self.bump();
+        }
+    }
+
+    fn skip_block_comment(&mut self) {
+        self.bump_n(2);
+        let mut depth = 1;
+        while depth > 0 {
+            let rest = self.rest();
+            if rest.starts_with("/*") {
+                depth += 1;
+                self.bump_n(2);
+            } else if rest.starts_with("*/") {
+                depth -= 1;
+                self.bump_n(2);
+            } else if self.bump().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Skips a character literal, or just the quote of a lifetime or label.
+    fn skip_char_or_lifetime(&mut self) {
+        self.bump();
+        let mut chars = self.rest().chars();
+        match (chars.next(), chars.next()) {
+            (Some('\\'), _) => {
+                self.bump();
+                self.bump();
+                while self.peek().is_some_and(|c| c != '\'') {
+                    self.bump();
+                }
+                self.bump();
+            }
+            (Some(_), Some('\'')) => self.bump_n(2),
+            _ => {}
+        }
+    }
+
+    /// Reads a normal string literal starting at the opening quote.
+    fn read_string(&mut self) -> Result<String, MalformedLiteral> {
+        self.bump();
+        let mut value = String::new();
+        let mut valid = true;
+        loop {
+            match self.bump().ok_or(MalformedLiteral::Unterminated)? {
+                '"' => return if valid { Ok(value) } else { Err(MalformedLiteral::InvalidEscape) },
+                '\\' => valid &= self.read_escape(&mut value).is_some(),
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Reads the escape after a backslash into `value`, returning `None` if it is invalid.
+    fn read_escape(&mut self, value: &mut String) -> Option<()> {
+        let c = match self.bump()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"' => '"',
+            'x' => {
+                // Exactly two hex digits, and only ASCII values are allowed in a `str`
+                let hex = self.rest().get(..2).filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))?;
+                let code = u8::from_str_radix(hex, 16).ok().filter(u8::is_ascii)?;
+                self.bump_n(2);
+                char::from(code)
+            }
+            'u' => {
+                // `{`, up to six hex digits with `_` separators after the first one, then `}`
+                let body = self.rest().strip_prefix('{')?;
+                let len = body.find(|c: char| !c.is_ascii_hexdigit() && c != '_').unwrap_or(body.len());
+                let digits = &body[..len];
+                if !body[len..].starts_with('}') || digits.starts_with('_') {
+                    return None;
+                }
+                let hex: String = digits.chars().filter(|&c| c != '_').collect();
+                if hex.is_empty() || hex.len() > 6 {
+                    return None;
+                }
+                let code = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)?;
+                self.bump_n(len + 2);
+                code
+            }
+            c if c == '\n' || (c == '\r' && self.peek() == Some('\n')) => {
+                // A line continuation skips the newline, LF or CRLF, and any leading whitespace
+                while self.peek().is_some_and(char::is_whitespace) {
+                    self.bump();
+                }
+                return Some(());
+            }
+            _ => return None,
+        };
+        value.push(c);
+        Some(())
+    }
+
+    /// Reads a raw string literal starting at the `r`.
+    fn read_raw_string(&mut self) -> Result<String, MalformedLiteral> {
+        self.bump();
+        let hashes = self.rest().len() - self.rest().trim_start_matches('#').len();
+        self.bump_n(hashes + 1);
+        let mut closing = String::from('"');
+        closing.push_str(&"#".repeat(hashes));
+        let Some(end) = self.rest().find(&closing) else {
+            self.bump_n(self.rest().chars().count());
+            return Err(MalformedLiteral::Unterminated);
+        };
+        let value: String = self.rest()[..end].into();
+        self.bump_n(value.chars().count() + closing.len());
+        Ok(value)
+    }
+
+    /// Returns the length of a raw string opener (`r"`, `r#"`, ...) at the current position.
+    fn raw_string_opener(&self) -> Option<usize> {
+        let after_r = self.rest().strip_prefix('r')?;
+        let after_hashes = after_r.trim_start_matches('#');
+        after_hashes
+            .starts_with('"')
+            .then(|| self.rest().len() - after_hashes.len() + 1)
+    }
+
+    fn previous_is_ident(&self) -> bool {
+        self.source[..self.pos].ends_with(is_ident_char)
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = StrLiteral;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self.rest();
+            let c = self.peek()?;
+            let (start, line, column) = (self.pos, self.line, self.column);
+
+            if rest.starts_with("//") {
+                self.skip_line_comment();
+            } else if rest.starts_with("/*") {
+                self.skip_block_comment();
+            } else if c == '\'' {
+                self.skip_char_or_lifetime();
+            } else if c == '"' {
+                let value = self.read_string();
+                return Some(StrLiteral { start, end: self.pos, line, column, value });
+            } else if c == 'r' && !self.previous_is_ident() && self.raw_string_opener().is_some() {
+                let value = self.read_raw_string();
+                return Some(StrLiteral { start, end: self.pos, line, column, value });
+            } else if c == 'b' && !self.previous_is_ident() && (rest.starts_with("b\"") || rest.starts_with("br")) {
+                // Byte strings are never passed to the string constructors, so skip them
+                self.bump();
+                if self.peek() == Some('"') {
+                    let _ = self.read_string();
+                } else if self.raw_string_opener().is_some() {
+                    let _ = self.read_raw_string();
+                }
+            } else if is_ident_char(c) {
+                while self.peek().is_some_and(is_ident_char) {
+                    self.bump();
+                }
+            } else {
+                self.bump();
+            }
+        }
+    }
+}
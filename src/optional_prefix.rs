@@ -0,0 +1,194 @@
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::{TypeIdPrefix, ValidationError};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A `TypeID` prefix that may be absent.
+///
+/// The `TypeID` specification allows identifiers without a prefix, such as
+/// `01h455vb4pex5vsknk084sn02q`. `OptionalPrefix` models that form: it is either a valid
+/// [`TypeIdPrefix`] or empty, and it is what sanitization returns when nothing in the input
+/// can be salvaged. A [`TypeIdPrefix`] itself is never empty.
+///
+/// It dereferences to `str`, where the empty form is `""`.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::prelude::*;
+///
+/// let prefix = "User".create_prefix_sanitized();
+/// assert_eq!(prefix.as_str(), "user");
+/// assert!(prefix.prefix().is_some());
+///
+/// let empty = "123".create_prefix_sanitized();
+/// assert!(empty.is_empty());
+/// assert_eq!(empty, OptionalPrefix::none());
+/// assert_eq!(TypeIdPrefix::try_from(empty), Err(ValidationError::IsEmpty));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OptionalPrefix(Option<TypeIdPrefix>);
+
+#[cfg(feature = "serde")]
+impl Serialize for OptionalPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Serialize OptionalPrefix as a string, which is empty when there is no prefix
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OptionalPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Deserialize as a string first
+        let s = String::deserialize(deserializer)?;
+
+        // Then accept the empty form or validate according to TypeID specification
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl OptionalPrefix {
+    /// Returns the empty form, for a `TypeID` without a prefix.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self(None)
+    }
+
+    /// Returns a string slice of the prefix, or `""` if there is none.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref().map_or("", TypeIdPrefix::as_str)
+    }
+
+    /// Returns the prefix, or `None` if there is none.
+    #[must_use]
+    pub const fn prefix(&self) -> Option<&TypeIdPrefix> {
+        self.0.as_ref()
+    }
+
+    /// Converts into the prefix, or `None` if there is none.
+    #[must_use]
+    pub fn into_prefix(self) -> Option<TypeIdPrefix> {
+        self.0
+    }
+}
+
+impl Deref for OptionalPrefix {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for OptionalPrefix {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for OptionalPrefix {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for OptionalPrefix {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for OptionalPrefix {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<TypeIdPrefix> for OptionalPrefix {
+    fn eq(&self, other: &TypeIdPrefix) -> bool {
+        self.0.as_ref() == Some(other)
+    }
+}
+
+impl PartialEq<OptionalPrefix> for TypeIdPrefix {
+    fn eq(&self, other: &OptionalPrefix) -> bool {
+        other == self
+    }
+}
+
+impl From<TypeIdPrefix> for OptionalPrefix {
+    fn from(prefix: TypeIdPrefix) -> Self {
+        Self(Some(prefix))
+    }
+}
+
+impl From<Option<TypeIdPrefix>> for OptionalPrefix {
+    fn from(prefix: Option<TypeIdPrefix>) -> Self {
+        Self(prefix)
+    }
+}
+
+impl From<OptionalPrefix> for Option<TypeIdPrefix> {
+    fn from(prefix: OptionalPrefix) -> Self {
+        prefix.0
+    }
+}
+
+impl TryFrom<OptionalPrefix> for TypeIdPrefix {
+    type Error = ValidationError;
+
+    /// Unwraps the prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::IsEmpty`] if there is no prefix.
+    fn try_from(prefix: OptionalPrefix) -> Result<Self, Self::Error> {
+        prefix.0.ok_or(ValidationError::IsEmpty)
+    }
+}
+
+/// Parses the empty string as [`OptionalPrefix::none`] and anything else as a `TypeIdPrefix`.
+///
+/// # Errors
+///
+/// Returns a `ValidationError` if the input is neither empty nor a valid `TypeID` prefix.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use typeid_prefix::OptionalPrefix;
+///
+/// assert!(OptionalPrefix::from_str("").unwrap().is_empty());
+/// assert_eq!(OptionalPrefix::from_str("user").unwrap(), "user");
+/// assert!(OptionalPrefix::from_str("_user").is_err());
+/// ```
+impl FromStr for OptionalPrefix {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::none());
+        }
+        TypeIdPrefix::validate(s).map(Self::from)
+    }
+}
+
+impl fmt::Display for OptionalPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,199 @@
+//! House rules layered on top of the `TypeID` specification.
+//!
+//! A [`PrefixPolicy`] first validates input against the specification, then applies any
+//! additional rules it was built with. Violations are reported as a [`PolicyError`], which
+//! either wraps the [`ValidationError`] from the specification rules or names the policy rule
+//! that failed.
+//!
+//! # Examples
+//!
+//! ```
+//! use typeid_prefix::policy::{PolicyError, PrefixPolicy};
+//! use typeid_prefix::ValidationError;
+//!
+//! let policy = PrefixPolicy::new()
+//!     .min_length(3)
+//!     .forbid_double_underscores()
+//!     .max_segments(3)
+//!     .deny(["admin", "system"]);
+//!
+//! assert_eq!(policy.validate("order_item").unwrap().as_str(), "order_item");
+//! assert_eq!(policy.validate("ab"), Err(PolicyError::TooShort { min_length: 3 }));
+//! assert_eq!(policy.validate("order__item"), Err(PolicyError::DoubleUnderscore));
+//! assert_eq!(policy.validate("a_b_c_d"), Err(PolicyError::TooManySegments { max_segments: 3 }));
+//! assert_eq!(policy.validate("admin"), Err(PolicyError::Denied { word: "admin".to_string() }));
+//! assert_eq!(policy.validate("Admin"), Err(PolicyError::Spec(ValidationError::InvalidStartCharacter)));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{SpecVersion, TypeIdPrefix, ValidationError};
+
+/// A set of rules a prefix must satisfy in addition to the `TypeID` specification.
+///
+/// A new policy applies only the rules of [`SpecVersion::LATEST`]. Each builder method adds
+/// one rule; rules are checked in the order the methods are documented below, after the
+/// specification rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixPolicy {
+    spec_version: SpecVersion,
+    min_length: Option<usize>,
+    forbid_double_underscores: bool,
+    max_segments: Option<usize>,
+    denylist: Vec<String>,
+}
+
+impl PrefixPolicy {
+    /// Creates a policy that only enforces the latest specification rules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates the specification rules of `version` instead of the latest version.
+    #[must_use]
+    pub const fn spec_version(mut self, version: SpecVersion) -> Self {
+        self.spec_version = version;
+        self
+    }
+
+    /// Requires prefixes to be at least `min_length` characters long.
+    #[must_use]
+    pub const fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Rejects prefixes containing two or more consecutive underscores.
+    #[must_use]
+    pub const fn forbid_double_underscores(mut self) -> Self {
+        self.forbid_double_underscores = true;
+        self
+    }
+
+    /// Limits prefixes to at most `max_segments` underscore-separated segments.
+    ///
+    /// Empty segments between consecutive underscores are not counted.
+    #[must_use]
+    pub const fn max_segments(mut self, max_segments: usize) -> Self {
+        self.max_segments = Some(max_segments);
+        self
+    }
+
+    /// Rejects prefixes that exactly match any of `words`.
+    ///
+    /// Calling this more than once adds to the denylist.
+    #[must_use]
+    pub fn deny<I>(mut self, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.denylist.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Validates `input` against the specification rules and then this policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolicyError::Spec`] if the input is not a valid prefix under the selected
+    /// [`SpecVersion`], or the variant naming the first policy rule the input violates.
+    pub fn validate(&self, input: &str) -> Result<TypeIdPrefix, PolicyError> {
+        let prefix = TypeIdPrefix::try_from_spec(input, self.spec_version)?;
+        self.check(&prefix)?;
+        Ok(prefix)
+    }
+
+    /// Checks an already validated prefix against the rules of this policy.
+    ///
+    /// The specification rules of the selected [`SpecVersion`] are not checked again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the variant naming the first policy rule the prefix violates.
+    pub fn check(&self, prefix: &TypeIdPrefix) -> Result<(), PolicyError> {
+        let prefix = prefix.as_str();
+
+        if let Some(min_length) = self.min_length {
+            if prefix.len() < min_length {
+                return Err(PolicyError::TooShort { min_length });
+            }
+        }
+
+        if self.forbid_double_underscores && prefix.contains("__") {
+            return Err(PolicyError::DoubleUnderscore);
+        }
+
+        if let Some(max_segments) = self.max_segments {
+            if prefix.split('_').filter(|segment| !segment.is_empty()).count() > max_segments {
+                return Err(PolicyError::TooManySegments { max_segments });
+            }
+        }
+
+        if let Some(word) = self.denylist.iter().find(|word| *word == prefix) {
+            return Err(PolicyError::Denied { word: word.clone() });
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors returned when a prefix violates a [`PrefixPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PolicyError {
+    /// The input is not a valid prefix according to the specification.
+    Spec(ValidationError),
+
+    /// The prefix is shorter than the policy's minimum length.
+    TooShort {
+        /// The minimum length required by the policy.
+        min_length: usize,
+    },
+
+    /// The prefix contains consecutive underscores.
+    DoubleUnderscore,
+
+    /// The prefix has more underscore-separated segments than the policy allows.
+    TooManySegments {
+        /// The maximum number of segments allowed by the policy.
+        max_segments: usize,
+    },
+
+    /// The prefix is on the policy's denylist.
+    Denied {
+        /// The denied word the prefix matched.
+        word: String,
+    },
+}
+
+impl From<ValidationError> for PolicyError {
+    fn from(error: ValidationError) -> Self {
+        Self::Spec(error)
+    }
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spec(error) => write!(f, "{error}"),
+            Self::TooShort { min_length } => write!(f, "Prefix must be at least {min_length} characters"),
+            Self::DoubleUnderscore => write!(f, "Prefix cannot contain consecutive underscores"),
+            Self::TooManySegments { max_segments } => {
+                write!(f, "Prefix cannot have more than {max_segments} segments")
+            }
+            Self::Denied { word } => write!(f, "Prefix '{word}' is a reserved word"),
+        }
+    }
+}
+
+impl core::error::Error for PolicyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Spec(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Serde helpers for choosing how `TypeIdPrefix` fields are deserialized.
+//!
+//! Each module can be used with `#[serde(with = "...")]` on a `TypeIdPrefix` field. All of
+//! them serialize the prefix as a plain string; they only differ in what they accept:
+//!
+//! - [`strict`] rejects anything that is not a valid prefix, like the `Deserialize` impl on
+//!   `TypeIdPrefix`.
+//! - [`sanitize`] cleans the input with
+//!   [`create_prefix_sanitized`](crate::prelude::PrefixFactory::create_prefix_sanitized), and
+//!   only fails if nothing can be salvaged.
+//! - [`lowercase`] folds ASCII uppercase letters to lowercase, then validates strictly.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Deserialize;
+//! use typeid_prefix::TypeIdPrefix;
+//!
+//! #[derive(Deserialize)]
+//! struct LegacyRecord {
+//!     #[serde(with = "typeid_prefix::serde_modes::sanitize")]
+//!     kind: TypeIdPrefix,
+//!     #[serde(with = "typeid_prefix::serde_modes::lowercase")]
+//!     owner: TypeIdPrefix,
+//! }
+//!
+//! let record: LegacyRecord = serde_json::from_str(r#"{"kind": "Order Item!", "owner": "USER"}"#).unwrap();
+//! assert_eq!(record.kind.as_str(), "orderitem");
+//! assert_eq!(record.owner.as_str(), "user");
+//! ```
+
+use serde::Serializer;
+
+use crate::TypeIdPrefix;
+
+fn serialize_prefix<S>(prefix: &TypeIdPrefix, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(prefix.as_str())
+}
+
+pub mod strict {
+    //! Accepts only valid prefixes.
+    //!
+    //! This is the behavior of the `Deserialize` impl on `TypeIdPrefix`, spelled out for
+    //! structs that mix deserialization modes.
+
+    use alloc::string::String;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::TypeIdPrefix;
+
+    /// Serializes the prefix as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if it cannot write a string.
+    pub fn serialize<S>(prefix: &TypeIdPrefix, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_prefix(prefix, serializer)
+    }
+
+    /// Deserializes a string and validates it without modification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a string or not a valid `TypeID` prefix.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeIdPrefix, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        TypeIdPrefix::validate(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+pub mod sanitize {
+    //! Sanitizes the input into a valid prefix.
+    //!
+    //! Invalid characters are removed, letters are lowercased and the result is truncated to
+    //! 63 characters. Input is only rejected if nothing in it can be salvaged.
+
+    use alloc::string::String;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::traits::PrefixFactory;
+    use crate::TypeIdPrefix;
+
+    /// Serializes the prefix as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if it cannot write a string.
+    pub fn serialize<S>(prefix: &TypeIdPrefix, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_prefix(prefix, serializer)
+    }
+
+    /// Deserializes a string and sanitizes it into a prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a string or sanitizes to an empty prefix.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeIdPrefix, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        TypeIdPrefix::try_from(s.create_prefix_sanitized()).map_err(serde::de::Error::custom)
+    }
+}
+
+pub mod lowercase {
+    //! Accepts prefixes written with ASCII uppercase letters.
+    //!
+    //! The input is folded to lowercase and then validated without any other modification,
+    //! so `"OrderItem"` becomes `"orderitem"` while `"Order Item"` is still rejected.
+
+    use alloc::string::String;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::TypeIdPrefix;
+
+    /// Serializes the prefix as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if it cannot write a string.
+    pub fn serialize<S>(prefix: &TypeIdPrefix, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_prefix(prefix, serializer)
+    }
+
+    /// Deserializes a string, folds it to lowercase and validates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a string or, once lowercased, not a valid
+    /// `TypeID` prefix.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeIdPrefix, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut s = String::deserialize(deserializer)?;
+        s.make_ascii_lowercase();
+        TypeIdPrefix::validate(&s).map_err(serde::de::Error::custom)
+    }
+}
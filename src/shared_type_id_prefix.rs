@@ -0,0 +1,274 @@
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::{validation, TypeIdPrefix, TypeIdPrefixRef, ValidationError};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A validated `TypeID` prefix that can be shared cheaply between threads and tasks.
+///
+/// `SharedTypeIdPrefix` upholds the same guarantees as [`TypeIdPrefix`], but stores the
+/// prefix in an `Arc<str>`. Cloning it only increments a reference count, which makes it
+/// a good fit for prefixes passed across async tasks. Validation, comparisons, `Display`
+/// and serde behave exactly as they do for [`TypeIdPrefix`].
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::{SharedTypeIdPrefix, TypeIdPrefix};
+/// use std::convert::TryFrom;
+///
+/// let prefix = SharedTypeIdPrefix::try_from("user").unwrap();
+/// let clone = prefix.clone();
+/// assert_eq!(clone, "user");
+///
+/// let owned: TypeIdPrefix = prefix.to_prefix();
+/// assert_eq!(owned, clone);
+///
+/// let invalid = SharedTypeIdPrefix::try_from("Invalid_Prefix");
+/// assert!(invalid.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedTypeIdPrefix(Arc<str>);
+
+#[cfg(feature = "serde")]
+impl Serialize for SharedTypeIdPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Serialize SharedTypeIdPrefix as a string
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SharedTypeIdPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Deserialize as a string first
+        let s = String::deserialize(deserializer)?;
+
+        // Then validate according to TypeID specification
+        Self::validate(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl PartialEq<str> for SharedTypeIdPrefix {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<SharedTypeIdPrefix> for str {
+    fn eq(&self, other: &SharedTypeIdPrefix) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<&str> for SharedTypeIdPrefix {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<SharedTypeIdPrefix> for &str {
+    fn eq(&self, other: &SharedTypeIdPrefix) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl PartialEq<String> for SharedTypeIdPrefix {
+    fn eq(&self, other: &String) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<SharedTypeIdPrefix> for String {
+    fn eq(&self, other: &SharedTypeIdPrefix) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<TypeIdPrefix> for SharedTypeIdPrefix {
+    fn eq(&self, other: &TypeIdPrefix) -> bool {
+        &*self.0 == other.as_str()
+    }
+}
+
+impl PartialEq<SharedTypeIdPrefix> for TypeIdPrefix {
+    fn eq(&self, other: &SharedTypeIdPrefix) -> bool {
+        self.as_str() == &*other.0
+    }
+}
+
+impl PartialEq<TypeIdPrefixRef<'_>> for SharedTypeIdPrefix {
+    fn eq(&self, other: &TypeIdPrefixRef<'_>) -> bool {
+        &*self.0 == other.as_str()
+    }
+}
+
+impl PartialEq<SharedTypeIdPrefix> for TypeIdPrefixRef<'_> {
+    fn eq(&self, other: &SharedTypeIdPrefix) -> bool {
+        self.as_str() == &*other.0
+    }
+}
+
+impl Borrow<str> for SharedTypeIdPrefix {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SharedTypeIdPrefix {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for SharedTypeIdPrefix {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for SharedTypeIdPrefix {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::validate(s)
+    }
+}
+
+impl TryFrom<&str> for SharedTypeIdPrefix {
+    type Error = ValidationError;
+
+    /// Attempts to create a `SharedTypeIdPrefix` from a string slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input string is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::SharedTypeIdPrefix;
+    /// use std::convert::TryFrom;
+    ///
+    /// let valid = SharedTypeIdPrefix::try_from("valid_prefix").unwrap();
+    /// assert_eq!(valid.as_str(), "valid_prefix");
+    ///
+    /// let invalid = SharedTypeIdPrefix::try_from("Invalid_Prefix");
+    /// assert!(invalid.is_err());
+    /// ```
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::validate(input)
+    }
+}
+
+impl TryFrom<String> for SharedTypeIdPrefix {
+    type Error = ValidationError;
+
+    /// Attempts to create a `SharedTypeIdPrefix` from a `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input string is not a valid `TypeID` prefix.
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        validation::check_bytes(input.as_bytes())?;
+        Ok(Self(Arc::from(input)))
+    }
+}
+
+impl From<TypeIdPrefix> for SharedTypeIdPrefix {
+    fn from(prefix: TypeIdPrefix) -> Self {
+        Self(Arc::from(prefix.as_str()))
+    }
+}
+
+impl From<&TypeIdPrefix> for SharedTypeIdPrefix {
+    fn from(prefix: &TypeIdPrefix) -> Self {
+        Self(Arc::from(prefix.as_str()))
+    }
+}
+
+impl From<TypeIdPrefixRef<'_>> for SharedTypeIdPrefix {
+    fn from(prefix: TypeIdPrefixRef<'_>) -> Self {
+        Self(Arc::from(prefix.as_str()))
+    }
+}
+
+impl From<SharedTypeIdPrefix> for TypeIdPrefix {
+    fn from(prefix: SharedTypeIdPrefix) -> Self {
+        prefix.to_prefix()
+    }
+}
+
+impl SharedTypeIdPrefix {
+    pub(crate) fn validate(input: &str) -> Result<Self, ValidationError> {
+        validation::check_bytes(input.as_bytes())?;
+        Ok(Self(Arc::from(input)))
+    }
+
+    /// Returns a string slice of the `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::SharedTypeIdPrefix;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = SharedTypeIdPrefix::try_from("valid_prefix").unwrap();
+    /// assert_eq!(prefix.as_str(), "valid_prefix");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Borrows the `TypeID` prefix as a [`TypeIdPrefixRef`].
+    #[must_use]
+    pub fn as_prefix_ref(&self) -> TypeIdPrefixRef<'_> {
+        TypeIdPrefixRef::new_unchecked(&self.0)
+    }
+
+    /// Converts the shared prefix into an owned [`TypeIdPrefix`].
+    ///
+    /// The prefix has already been validated, so this only copies the string.
+    #[must_use]
+    pub fn to_prefix(&self) -> TypeIdPrefix {
+        self.as_prefix_ref().to_prefix()
+    }
+
+    /// Returns `true` if both prefixes point to the same allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::SharedTypeIdPrefix;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = SharedTypeIdPrefix::try_from("user").unwrap();
+    /// assert!(SharedTypeIdPrefix::ptr_eq(&prefix, &prefix.clone()));
+    /// assert!(!SharedTypeIdPrefix::ptr_eq(&prefix, &SharedTypeIdPrefix::try_from("user").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl fmt::Display for SharedTypeIdPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
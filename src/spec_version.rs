@@ -0,0 +1,70 @@
+use core::fmt;
+
+use crate::validation::MAX_LENGTH;
+
+/// A version of the `TypeID` specification, selecting which prefix rules apply.
+///
+/// The prefix rules have changed between versions of the specification. Version 0.2 only
+/// allowed lowercase ASCII letters, while version 0.3 also allows underscores between them.
+/// Validation and sanitization use [`SpecVersion::LATEST`] unless a version is passed
+/// explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::{SpecVersion, TypeIdPrefixRef, ValidationError};
+///
+/// assert!(TypeIdPrefixRef::try_from_spec("user_account", SpecVersion::V0_3).is_ok());
+/// assert_eq!(
+///     TypeIdPrefixRef::try_from_spec("user_account", SpecVersion::V0_2),
+///     Err(ValidationError::ContainsInvalidCharacters)
+/// );
+///
+/// let rules = SpecVersion::V0_2.rules();
+/// assert_eq!(rules.max_length, 63);
+/// assert!(!rules.allows_underscores);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum SpecVersion {
+    /// Version 0.2: prefixes contain only lowercase ASCII letters.
+    V0_2,
+    /// Version 0.3: prefixes may also contain underscores, but not at the start or end.
+    #[default]
+    V0_3,
+}
+
+impl SpecVersion {
+    /// The most recent version of the specification, used by default.
+    pub const LATEST: Self = Self::V0_3;
+
+    /// Returns the prefix rules defined by this version of the specification.
+    #[must_use]
+    pub const fn rules(self) -> PrefixRules {
+        match self {
+            Self::V0_2 => PrefixRules { max_length: MAX_LENGTH, allows_underscores: false },
+            Self::V0_3 => PrefixRules { max_length: MAX_LENGTH, allows_underscores: true },
+        }
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match self {
+            Self::V0_2 => "0.2",
+            Self::V0_3 => "0.3",
+        };
+        write!(f, "{version}")
+    }
+}
+
+/// The prefix rules of a [`SpecVersion`].
+///
+/// Every version requires prefixes to be non-empty and to start and end with a lowercase
+/// ASCII letter; the fields describe what differs between versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrefixRules {
+    /// The maximum length of a prefix, in bytes.
+    pub max_length: usize,
+    /// Whether underscores are allowed between the first and last character.
+    pub allows_underscores: bool,
+}
@@ -0,0 +1,88 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for valid and near-valid `TypeID` prefixes.
+//!
+//! These strategies let downstream crates property-test code that handles prefixes without
+//! writing their own regular expressions. Every generated value, including shrunk values, is
+//! guaranteed to have the property its strategy promises.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::test_runner::TestRunner;
+//! use typeid_prefix::strategies::{any_invalid_prefix_for, any_valid_prefix};
+//! use typeid_prefix::{TypeIdPrefix, ValidationError};
+//!
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&any_valid_prefix(), |prefix| {
+//!         assert_eq!(TypeIdPrefix::try_from(prefix.as_str()), Ok(prefix));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//!
+//! let leading_underscore = any_invalid_prefix_for(ValidationError::StartsWithUnderscore);
+//! runner
+//!     .run(&leading_underscore, |input| {
+//!         assert_eq!(TypeIdPrefix::try_from(input), Err(ValidationError::StartsWithUnderscore));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+
+use proptest::prelude::*;
+use proptest::string::string_regex;
+
+use crate::{TypeIdPrefix, ValidationError};
+
+// Printable ASCII, and printable ASCII without `_` or without `_` and lowercase letters.
+const ASCII: &str = "[ -~]";
+const NOT_UNDERSCORE: &str = "[ -^`-~]";
+const NOT_LOWERCASE_OR_UNDERSCORE: &str = "[ -^`{-~]";
+
+fn regex(pattern: &str) -> BoxedStrategy<String> {
+    string_regex(pattern)
+        .expect("strategy patterns are valid regular expressions")
+        .boxed()
+}
+
+/// Generates valid prefixes of every length from 1 to 63.
+pub fn any_valid_prefix() -> impl Strategy<Value = TypeIdPrefix> {
+    regex("[a-z]([a-z_]{0,61}[a-z])?").prop_map(TypeIdPrefix::new_unchecked)
+}
+
+/// Generates inputs that fail validation with exactly `error`.
+///
+/// The inputs respect the order in which the validator checks its rules, so for example
+/// inputs for [`ValidationError::InvalidEndCharacter`] are short enough, ASCII, and start
+/// with a lowercase letter.
+pub fn any_invalid_prefix_for(error: ValidationError) -> BoxedStrategy<String> {
+    match error {
+        ValidationError::ExceedsMaxLength => prop_oneof![regex("[a-z_]{64,100}"), regex("\\PC{64,100}")].boxed(),
+        ValidationError::IsEmpty => Just(String::new()).boxed(),
+        ValidationError::ContainsInvalidCharacters => prop_oneof![
+            // Any non-ASCII character takes precedence over the other rules
+            regex(&format!("{ASCII}{{0,20}}[^\\x00-\\x7F]{ASCII}{{0,20}}")),
+            // Otherwise an invalid ASCII character between valid start and end letters
+            regex(&format!("[a-z][a-z_]{{0,20}}{NOT_LOWERCASE_OR_UNDERSCORE}{{1,3}}[a-z_]{{0,20}}[a-z]")),
+        ]
+        .boxed(),
+        ValidationError::StartsWithUnderscore => regex(&format!("_{ASCII}{{0,40}}")),
+        ValidationError::EndsWithUnderscore => regex(&format!("{NOT_UNDERSCORE}{ASCII}{{0,40}}_")),
+        ValidationError::InvalidStartCharacter => {
+            regex(&format!("{NOT_LOWERCASE_OR_UNDERSCORE}({ASCII}{{0,40}}{NOT_UNDERSCORE})?"))
+        }
+        ValidationError::InvalidEndCharacter => {
+            regex(&format!("[a-z]{ASCII}{{0,40}}{NOT_LOWERCASE_OR_UNDERSCORE}"))
+        }
+    }
+}
+
+/// Generates arbitrary text that sanitizes to a non-empty, valid prefix.
+///
+/// Each input contains at least one ASCII letter early enough to survive truncation, mixed
+/// with uppercase letters, digits, punctuation, whitespace and non-ASCII characters.
+pub fn any_sanitizable_input() -> impl Strategy<Value = String> {
+    regex("\\PC{0,20}[a-zA-Z]\\PC{0,40}")
+}
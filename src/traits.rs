@@ -1,6 +1,8 @@
+#[cfg(feature = "alloc")]
 pub use prefix_factory::PrefixFactory;
 pub use validate::Validate;
 
+#[cfg(feature = "alloc")]
 mod prefix_factory;
 mod validate;
 
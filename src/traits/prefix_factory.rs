@@ -1,11 +1,12 @@
-use std::str::FromStr;
+use core::str::FromStr;
 
-use crate::{TypeIdPrefix, ValidationError};
+use crate::{OptionalPrefix, SpecVersion, TypeIdPrefix, ValidationError};
 
 /// A trait for creating valid `TypeIdPrefix`s from a given input.
 ///
 /// This trait is implemented for any type that can be converted to a string slice (`AsRef<str>`).
 /// It provides a method to clean and create a valid `TypeIdPrefix`, even from invalid input as well as a fallible creation method.
+/// Sanitization returns an [`OptionalPrefix`], since some inputs contain nothing that can be salvaged.
 ///
 /// # Examples
 ///
@@ -16,7 +17,7 @@ use crate::{TypeIdPrefix, ValidationError};
 /// assert_eq!(sanitized.as_str(), "invalidstring");
 /// ```
 pub trait PrefixFactory {
-    /// Sanitizes the input and creates a valid `TypeIdPrefix`.
+    /// Sanitizes the input and creates a valid `TypeIdPrefix`, if anything can be salvaged.
     ///
     /// This method will modify the input to conform to the `TypeID` specification by:
     /// - Removing invalid characters
@@ -44,15 +45,18 @@ pub trait PrefixFactory {
     ///
     /// # Return Value
     ///
-    /// - If the input can be sanitized into a valid prefix, returns a `TypeIdPrefix` containing the sanitized value.
+    /// - If the input can be sanitized into a valid prefix, returns an `OptionalPrefix` containing the sanitized value.
     /// - If the input is invalid and cannot be sanitized into a valid prefix (e.g., contains no valid characters),
-    ///   returns an empty `TypeIdPrefix`.
+    ///   returns [`OptionalPrefix::none`].
     ///
     /// # Note
     ///
-    /// This method will always return a `TypeIdPrefix`, even if it's empty. If you need to ensure
+    /// This method will always return an `OptionalPrefix`, even if it's empty. If you need to ensure
     /// the input is valid without modification, use `try_create_prefix` instead.
-    fn create_prefix_sanitized(&self) -> TypeIdPrefix
+    ///
+    /// The result is the same as [`TypeIdPrefix::canonicalize`], which documents the guarantees
+    /// this method provides: valid input is returned unchanged and sanitizing is idempotent.
+    fn create_prefix_sanitized(&self) -> OptionalPrefix
     where
         Self: AsRef<str>;
 
@@ -93,6 +97,45 @@ pub trait PrefixFactory {
     fn try_create_prefix(&self) -> Result<TypeIdPrefix, ValidationError>
     where
         Self: AsRef<str>;
+
+    /// Sanitizes the input under the prefix rules of a specific [`SpecVersion`].
+    ///
+    /// This behaves like `create_prefix_sanitized`, which uses [`SpecVersion::LATEST`], except
+    /// that underscores are removed as well under versions that do not allow them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// let prefix = "User_Account".create_prefix_sanitized_with(SpecVersion::V0_2);
+    /// assert_eq!(prefix.as_str(), "useraccount");
+    ///
+    /// let prefix = "User_Account".create_prefix_sanitized_with(SpecVersion::V0_3);
+    /// assert_eq!(prefix.as_str(), "user_account");
+    /// ```
+    fn create_prefix_sanitized_with(&self, version: SpecVersion) -> OptionalPrefix
+    where
+        Self: AsRef<str>;
+
+    /// Attempts to create a `TypeIdPrefix` from the input under the prefix rules of a
+    /// specific [`SpecVersion`], without modifying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// assert!("user_account".try_create_prefix_with(SpecVersion::V0_2).is_err());
+    /// assert!("user_account".try_create_prefix_with(SpecVersion::V0_3).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid prefix under `version`.
+    fn try_create_prefix_with(&self, version: SpecVersion) -> Result<TypeIdPrefix, ValidationError>
+    where
+        Self: AsRef<str>;
 }
 
 #[allow(unused_variables)]
@@ -100,15 +143,24 @@ impl<T> PrefixFactory for T
 where
     T: AsRef<str>,
 {
-    fn create_prefix_sanitized(&self) -> TypeIdPrefix {
-        let input = TypeIdPrefix::clean_inner(self.as_ref());
-        TypeIdPrefix::validate(&input).unwrap_or_else(|e| {
-            #[cfg(feature = "instrument")]
-            tracing::warn!("Invalid TypeIdPrefix: {:?}. Using empty string instead.", e);
-            TypeIdPrefix::default()
-        })
+    fn create_prefix_sanitized(&self) -> OptionalPrefix {
+        self.create_prefix_sanitized_with(SpecVersion::LATEST)
     }
     fn try_create_prefix(&self) -> Result<TypeIdPrefix, ValidationError> {
         TypeIdPrefix::from_str(self.as_ref())
     }
+    fn create_prefix_sanitized_with(&self, version: SpecVersion) -> OptionalPrefix {
+        let input = TypeIdPrefix::clean_inner(self.as_ref(), version);
+        TypeIdPrefix::try_from_spec(&input, version).map_or_else(
+            |e| {
+                #[cfg(feature = "instrument")]
+                tracing::warn!("Invalid TypeIdPrefix: {:?}. Using no prefix instead.", e);
+                OptionalPrefix::none()
+            },
+            OptionalPrefix::from,
+        )
+    }
+    fn try_create_prefix_with(&self, version: SpecVersion) -> Result<TypeIdPrefix, ValidationError> {
+        TypeIdPrefix::try_from_spec(self.as_ref(), version)
+    }
 }
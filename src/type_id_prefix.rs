@@ -1,9 +1,12 @@
-use std::borrow::Borrow;
-use std::fmt;
-use std::ops::Deref;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
 
-use crate::ValidationError;
+use crate::traits::PrefixFactory;
+use crate::{validation, OptionalPrefix, SpecVersion, TypeIdPrefixRef, ValidationError};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -11,7 +14,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// Represents a valid `TypeID` prefix as defined by the `TypeID` specification.
 ///
 /// A `TypeIdPrefix` is guaranteed to:
-/// - Have a maximum length of 63 characters
+/// - Have a length of 1 to 63 characters
 /// - Contain only lowercase ASCII letters and underscores
 /// - Not start or end with an underscore
 /// - Start and end with a lowercase letter
@@ -28,8 +31,16 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// let invalid = TypeIdPrefix::try_from("Invalid_Prefix");
 /// assert!(invalid.is_err());
 /// ```
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+///
+/// A `TypeIdPrefix` is never empty, so it does not implement `Default`. Use
+/// [`OptionalPrefix`] for the `TypeID` form without a prefix.
+///
+/// ```compile_fail
+/// use typeid_prefix::TypeIdPrefix;
+///
+/// let empty = TypeIdPrefix::default();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeIdPrefix(String);
 
 #[cfg(feature = "serde")]
@@ -57,6 +68,122 @@ impl<'de> Deserialize<'de> for TypeIdPrefix {
     }
 }
 
+/// A `TypeID` prefix: 1 to 63 characters, lowercase ASCII letters and underscores only,
+/// starting and ending with a lowercase letter.
+#[cfg(feature = "async-graphql")]
+#[async_graphql::Scalar(
+    name = "TypeIdPrefix",
+    specified_by_url = "https://github.com/jetify-com/typeid/blob/main/spec/README.md"
+)]
+impl async_graphql::ScalarType for TypeIdPrefix {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        match value {
+            // Validate according to TypeID specification, surfacing the error message to the client
+            async_graphql::Value::String(s) => {
+                Self::validate(&s).map_err(async_graphql::InputValueError::custom)
+            }
+            other => Err(async_graphql::InputValueError::expected_type(other)),
+        }
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(s) if Self::validate(s).is_ok())
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(self.0.clone())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TypeIdPrefix {
+    /// Generates a valid prefix of 1 to 63 characters.
+    ///
+    /// The first and last characters are always lowercase letters, and each character in
+    /// between is an underscore about a quarter of the time, so runs of underscores and
+    /// single-letter segments are both common.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(1..=validation::MAX_LENGTH)?;
+        let mut prefix = String::with_capacity(len);
+        for i in 0..len {
+            let is_edge = i == 0 || i == len - 1;
+            if !is_edge && u.ratio(1_u8, 4_u8)? {
+                prefix.push('_');
+            } else {
+                prefix.push(char::from(b'a' + u.int_in_range(0..=25)?));
+            }
+        }
+        debug_assert!(Self::check(&prefix).is_ok());
+        Ok(Self(prefix))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        // An exhausted `Unstructured` still yields a prefix; otherwise one byte for the
+        // length, one per edge letter and up to two per inner character
+        (0, Some(1 + 2 + 2 * (validation::MAX_LENGTH - 2)))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for TypeIdPrefix {
+    /// Generates a valid prefix no longer than the generator's size, capped at 63 characters.
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        let max_len = g.size().clamp(1, validation::MAX_LENGTH);
+        let len = 1 + <usize as quickcheck::Arbitrary>::arbitrary(g) % max_len;
+        let mut prefix = String::with_capacity(len);
+        for i in 0..len {
+            let is_edge = i == 0 || i == len - 1;
+            if !is_edge && <u8 as quickcheck::Arbitrary>::arbitrary(g) % 4 == 0 {
+                prefix.push('_');
+            } else {
+                prefix.push(char::from(*g.choose(LETTERS).unwrap_or(&b'a')));
+            }
+        }
+        Self(prefix)
+    }
+
+    /// Shrinks toward short prefixes made of `a`s, such as `"a"` or `"a_a"`.
+    ///
+    /// Candidates drop trailing parts, whole segments or single characters, then replace
+    /// letters with `a`. Every candidate is a valid prefix that is shorter than, or the same
+    /// length and lexicographically smaller than, the current one, so shrinking terminates.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let current = self.as_str();
+        let len = current.len();
+        let mut candidates: Vec<String> = Vec::new();
+
+        for keep in [1, len / 2, len - 1] {
+            candidates.push(current[..keep].to_string());
+        }
+        let mut start = 0;
+        for segment in current.split('_') {
+            let end = start + segment.len();
+            let mut without = String::from(&current[..start]);
+            without.push_str(current[end..].trim_start_matches('_'));
+            candidates.push(without.trim_end_matches('_').to_string());
+            start = end + 1;
+        }
+        for i in 0..len {
+            candidates.push([&current[..i], &current[i + 1..]].concat());
+        }
+        candidates.push(current.replace(|c: char| c.is_ascii_lowercase(), "a"));
+        for (i, c) in current.char_indices().filter(|&(_, c)| c.is_ascii_lowercase() && c != 'a') {
+            let mut simpler = current.to_string();
+            simpler.replace_range(i..i + c.len_utf8(), "a");
+            candidates.push(simpler);
+        }
+
+        let mut shrunk: Vec<Self> = Vec::new();
+        for candidate in candidates {
+            let is_smaller = (candidate.len(), candidate.as_str()) < (len, current);
+            if is_smaller && Self::check(&candidate).is_ok() && !shrunk.iter().any(|s| s.0 == candidate) {
+                shrunk.push(Self(candidate));
+            }
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
 
 impl PartialEq<str> for TypeIdPrefix {
     fn eq(&self, other: &str) -> bool {
@@ -206,45 +333,257 @@ impl TryFrom<&str> for TypeIdPrefix
     }
 }
 
+impl TryFrom<&[u8]> for TypeIdPrefix
+{
+    type Error = ValidationError;
+
+    /// Attempts to create a `TypeIdPrefix` from a byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input bytes are not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::TypeIdPrefix;
+    /// use std::convert::TryFrom;
+    ///
+    /// let valid = TypeIdPrefix::try_from(&b"valid_prefix"[..]).unwrap();
+    /// assert_eq!(valid.as_str(), "valid_prefix");
+    ///
+    /// let invalid = TypeIdPrefix::try_from(&b"Invalid_Prefix"[..]);
+    /// assert!(invalid.is_err());
+    /// ```
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(input)
+    }
+}
+
+impl TryFrom<Vec<u8>> for TypeIdPrefix
+{
+    type Error = ValidationError;
+
+    /// Attempts to create a `TypeIdPrefix` from a byte vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input bytes are not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::TypeIdPrefix;
+    /// use std::convert::TryFrom;
+    ///
+    /// let valid = TypeIdPrefix::try_from(b"valid_prefix".to_vec()).unwrap();
+    /// assert_eq!(valid.as_str(), "valid_prefix");
+    ///
+    /// let invalid = TypeIdPrefix::try_from(b"Invalid_Prefix".to_vec());
+    /// assert!(invalid.is_err());
+    /// ```
+    fn try_from(input: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(&input)
+    }
+}
 
 impl TypeIdPrefix {
     pub(crate) fn validate(input: &str) -> Result<Self, ValidationError> {
-        if input.len() > 63 {
-            return Err(ValidationError::ExceedsMaxLength);
-        }
+        Self::check(input)?;
+        Ok(Self(input.to_string()))
+    }
 
-        if input.is_empty() {
-            return Err(ValidationError::IsEmpty);
-        }
+    pub(crate) const fn new_unchecked(input: String) -> Self {
+        Self(input)
+    }
 
-        if !input.is_ascii() {
-            return Err(ValidationError::ContainsInvalidCharacters);
-        }
+    /// Checks whether `input` is a valid `TypeID` prefix without allocating.
+    ///
+    /// This reports the same errors as `TryFrom<&str>`, but does not build a `TypeIdPrefix`.
+    /// Being a `const fn`, it can also be used in const contexts.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// assert_eq!(TypeIdPrefix::check("user_account"), Ok(()));
+    /// assert_eq!(TypeIdPrefix::check("_user"), Err(ValidationError::StartsWithUnderscore));
+    ///
+    /// const CHECKED: Result<(), ValidationError> = TypeIdPrefix::check("user");
+    /// assert!(CHECKED.is_ok());
+    /// ```
+    pub const fn check(input: &str) -> Result<(), ValidationError> {
+        Self::check_bytes(input.as_bytes())
+    }
 
-        if input.starts_with('_') {
-            return Err(ValidationError::StartsWithUnderscore);
-        }
+    /// Returns `true` if `input` is a valid `TypeID` prefix, without allocating.
+    ///
+    /// Being a `const fn`, it can be used in static assertions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// const _: () = assert!(TypeIdPrefix::is_valid("user_account"));
+    /// const _: () = assert!(!TypeIdPrefix::is_valid("User_Account"));
+    ///
+    /// assert!(!TypeIdPrefix::is_valid(""));
+    /// ```
+    #[must_use]
+    pub const fn is_valid(input: &str) -> bool {
+        Self::check(input).is_ok()
+    }
 
-        if input.ends_with('_') {
-            return Err(ValidationError::EndsWithUnderscore);
-        }
+    pub(crate) const fn check_bytes(input: &[u8]) -> Result<(), ValidationError> {
+        validation::check_bytes(input)
+    }
 
-        if !input.starts_with(|c: char| c.is_ascii_lowercase()) {
-            return Err(ValidationError::InvalidStartCharacter);
-        }
+    /// Attempts to create a `TypeIdPrefix` from a byte slice.
+    ///
+    /// The `TypeID` rules are checked directly on the bytes, so the input does not need to be
+    /// converted to a `&str` first. Any byte outside the ASCII range is reported as
+    /// [`ValidationError::ContainsInvalidCharacters`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input bytes are not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// let prefix = TypeIdPrefix::try_from_bytes(b"valid_prefix").unwrap();
+    /// assert_eq!(prefix.as_str(), "valid_prefix");
+    ///
+    /// let invalid = TypeIdPrefix::try_from_bytes(&[0xF0, 0x9F, 0x8C, 0x80]);
+    /// assert_eq!(invalid.unwrap_err(), ValidationError::ContainsInvalidCharacters);
+    /// ```
+    pub fn try_from_bytes(input: &[u8]) -> Result<Self, ValidationError> {
+        Self::check_bytes(input)?;
+        Ok(Self::from_ascii_unchecked(input))
+    }
 
-        if !input.ends_with(|c: char| c.is_ascii_lowercase()) {
-            return Err(ValidationError::InvalidEndCharacter);
-        }
+    /// Attempts to create a `TypeIdPrefix` under the prefix rules of a specific [`SpecVersion`].
+    ///
+    /// The other constructors validate against [`SpecVersion::LATEST`]. Underscores are
+    /// reported as [`ValidationError::ContainsInvalidCharacters`] under versions that do not
+    /// allow them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid prefix under `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// assert!(TypeIdPrefix::try_from_spec("user", SpecVersion::V0_2).is_ok());
+    /// assert!(TypeIdPrefix::try_from_spec("user_account", SpecVersion::V0_2).is_err());
+    /// assert!(TypeIdPrefix::try_from_spec("user_account", SpecVersion::V0_3).is_ok());
+    /// ```
+    pub fn try_from_spec(input: &str, version: SpecVersion) -> Result<Self, ValidationError> {
+        validation::check_bytes_with(input.as_bytes(), version)?;
+        Ok(Self(input.to_string()))
+    }
+
+    /// Derives a prefix from the name of the type `T`.
+    ///
+    /// The name reported by [`core::any::type_name`] is stripped of its module path and generic
+    /// arguments, converted from `CamelCase` to `snake_case` and validated.
+    ///
+    /// The result, including a validation failure, is computed once per type and kept for the
+    /// rest of the process, so repeated calls return the same `&'static` prefix without
+    /// allocating. The cache is a `RwLock`-guarded map shared by all threads, which is why this
+    /// method requires the `std` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the converted name is not a valid `TypeID` prefix, for
+    /// example because it contains digits or is longer than 63 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// struct OrderItem;
+    /// struct Page<T>(Vec<T>);
+    ///
+    /// assert_eq!(TypeIdPrefix::of::<OrderItem>().unwrap().as_str(), "order_item");
+    /// assert_eq!(TypeIdPrefix::of::<Page<OrderItem>>().unwrap().as_str(), "page");
+    /// assert_eq!(TypeIdPrefix::of::<u32>(), Err(ValidationError::InvalidEndCharacter));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn of<T: ?Sized + 'static>() -> Result<&'static Self, ValidationError> {
+        crate::type_name::prefix_of::<T>()
+    }
 
-        if !input.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
-            return Err(ValidationError::ContainsInvalidCharacters);
+    /// Converts any input into its canonical prefix.
+    ///
+    /// This is the sanitization performed by
+    /// [`PrefixFactory::create_prefix_sanitized`](crate::prelude::PrefixFactory::create_prefix_sanitized),
+    /// with two guarantees that callers may rely on:
+    ///
+    /// - **Valid input is unchanged**: if `input` is a valid prefix, the result is `input`.
+    /// - **Idempotence**: `canonicalize(canonicalize(x).as_str()) == canonicalize(x)` for every `x`.
+    ///
+    /// The result is an [`OptionalPrefix`] holding a valid prefix, or empty when nothing in the
+    /// input can be salvaged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// let canonical = TypeIdPrefix::canonicalize("_User Account_");
+    /// assert_eq!(canonical.as_str(), "useraccount");
+    /// assert_eq!(TypeIdPrefix::canonicalize(canonical.as_str()), canonical);
+    ///
+    /// assert_eq!(TypeIdPrefix::canonicalize("user_account").as_str(), "user_account");
+    /// assert!(TypeIdPrefix::canonicalize("123").is_empty());
+    /// ```
+    #[must_use]
+    pub fn canonicalize(input: &str) -> OptionalPrefix {
+        if Self::is_valid(input) {
+            return Self(input.to_string()).into();
         }
+        input.create_prefix_sanitized()
+    }
 
-        Ok(Self(input.to_string()))
+    /// Returns `true` if [`canonicalize`](Self::canonicalize) would return `input` unchanged.
+    ///
+    /// That is the case for every valid prefix and for the empty string. The check does not
+    /// allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    ///
+    /// assert!(TypeIdPrefix::is_canonical("user_account"));
+    /// assert!(!TypeIdPrefix::is_canonical("User_Account"));
+    /// assert!(!TypeIdPrefix::is_canonical("_user"));
+    /// ```
+    #[must_use]
+    pub const fn is_canonical(input: &str) -> bool {
+        input.is_empty() || Self::is_valid(input)
     }
 
-    pub(crate) fn clean_inner(input: &str) -> String {
+    pub(crate) fn from_ascii_unchecked(input: &[u8]) -> Self {
+        // The bytes are known to be ASCII, so each one maps directly to a `char`
+        Self(input.iter().copied().map(char::from).collect())
+    }
+
+    pub(crate) fn clean_inner(input: &str, version: SpecVersion) -> String {
+        let allows_underscores = version.rules().allows_underscores;
         let mut result = input.to_string();
         result = result.to_lowercase();
         // Safely truncate to 63 characters if necessary
@@ -253,7 +592,7 @@ impl TypeIdPrefix {
         }
 
         result = result.to_ascii_lowercase().chars()
-            .filter(|&c| (c.is_ascii_lowercase() || c == '_') && c.is_ascii())
+            .filter(|&c| (c.is_ascii_lowercase() || (allows_underscores && c == '_')) && c.is_ascii())
             .collect::<String>();
 
         // Remove leading and trailing underscores safely using trim_matches
@@ -263,6 +602,14 @@ impl TypeIdPrefix {
         result
     }
 
+    /// Exposes the raw output of `clean_inner` to the Kani proofs in `tests/proofs.rs`.
+    #[cfg(kani)]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn clean_inner_for_proofs(input: &str, version: SpecVersion) -> String {
+        Self::clean_inner(input, version)
+    }
+
     /// Returns a string slice of the `TypeID` prefix.
     ///
     /// # Examples
@@ -279,6 +626,23 @@ impl TypeIdPrefix {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Borrows the `TypeID` prefix as a [`TypeIdPrefixRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::prelude::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = TypeIdPrefix::try_from("valid_prefix").unwrap();
+    /// let borrowed = prefix.as_prefix_ref();
+    /// assert_eq!(borrowed, prefix);
+    /// ```
+    #[must_use]
+    pub fn as_prefix_ref(&self) -> TypeIdPrefixRef<'_> {
+        TypeIdPrefixRef::new_unchecked(&self.0)
+    }
 }
 
 
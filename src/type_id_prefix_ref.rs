@@ -0,0 +1,300 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+
+#[cfg(feature = "alloc")]
+use crate::TypeIdPrefix;
+use crate::{validation, SpecVersion, ValidationError};
+
+#[cfg(feature = "serde")]
+use serde::de::{self, Unexpected, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A borrowed, validated `TypeID` prefix.
+///
+/// `TypeIdPrefixRef` upholds the same guarantees as [`TypeIdPrefix`], but wraps a `&str`
+/// instead of owning a `String`. This makes it possible to validate a slice of a larger
+/// buffer without allocating, and it is available even when the crate is built without
+/// the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_prefix::TypeIdPrefixRef;
+/// use std::convert::TryFrom;
+///
+/// let buffer = "user_01h455vb4pex5vsknk084sn02q";
+/// let prefix = TypeIdPrefixRef::try_from(&buffer[..4]).unwrap();
+/// assert_eq!(prefix, "user");
+///
+/// let invalid = TypeIdPrefixRef::try_from("Invalid_Prefix");
+/// assert!(invalid.is_err());
+/// ```
+///
+/// # Deserialization Borrows Only
+///
+/// With the `serde` feature, `TypeIdPrefixRef` can only be deserialized when the string can be
+/// borrowed straight from the input. Deserialization **fails even for valid prefixes** when the
+/// deserializer has to build the string itself. The error then names `TypeIdPrefix` as the
+/// owned alternative. This happens for example with:
+///
+/// - JSON strings containing escape sequences, such as `"user\u005faccount"`
+/// - deserializers that own their strings, such as an owned `serde_json::Value`
+///
+/// Functions that require `DeserializeOwned`, such as `serde_json::from_reader` and
+/// `serde_json::from_value`, do not accept `TypeIdPrefixRef` at all. Deserialize into
+/// [`TypeIdPrefix`] instead whenever the input is not a `&str` or `&[u8]` kept alive by the
+/// caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeIdPrefixRef<'a>(&'a str);
+
+#[cfg(feature = "serde")]
+impl Serialize for TypeIdPrefixRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Serialize TypeIdPrefixRef as a string
+        serializer.serialize_str(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> Deserialize<'de> for TypeIdPrefixRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RefVisitor)
+    }
+}
+
+/// Accepts only strings borrowed from the input, and points to `TypeIdPrefix` otherwise.
+#[cfg(feature = "serde")]
+struct RefVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for RefVisitor {
+    type Value = TypeIdPrefixRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a valid TypeID prefix borrowed from the input")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        TypeIdPrefixRef::validate(v).map_err(E::custom)
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        let s = core::str::from_utf8(v).map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))?;
+        self.visit_borrowed_str(s)
+    }
+
+    // Escaped strings and readers or values that own their data end up here
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Err(E::custom(format_args!(
+            "TypeIdPrefixRef must borrow from the input, but {v:?} had to be copied \
+             (for example because it contains escapes); deserialize into TypeIdPrefix instead"
+        )))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Err(E::custom(format_args!(
+            "TypeIdPrefixRef must borrow from the input, but {} bytes had to be copied; \
+             deserialize into TypeIdPrefix instead",
+            v.len()
+        )))
+    }
+}
+
+impl PartialEq<str> for TypeIdPrefixRef<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<TypeIdPrefixRef<'_>> for str {
+    fn eq(&self, other: &TypeIdPrefixRef<'_>) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<&str> for TypeIdPrefixRef<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<TypeIdPrefixRef<'_>> for &str {
+    fn eq(&self, other: &TypeIdPrefixRef<'_>) -> bool {
+        *self == other.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<String> for TypeIdPrefixRef<'_> {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<TypeIdPrefixRef<'_>> for String {
+    fn eq(&self, other: &TypeIdPrefixRef<'_>) -> bool {
+        self == other.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<TypeIdPrefix> for TypeIdPrefixRef<'_> {
+    fn eq(&self, other: &TypeIdPrefix) -> bool {
+        self.0 == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<TypeIdPrefixRef<'_>> for TypeIdPrefix {
+    fn eq(&self, other: &TypeIdPrefixRef<'_>) -> bool {
+        self.as_str() == other.0
+    }
+}
+
+impl Borrow<str> for TypeIdPrefixRef<'_> {
+    fn borrow(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for TypeIdPrefixRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Deref for TypeIdPrefixRef<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TypeIdPrefixRef<'a> {
+    type Error = ValidationError;
+
+    /// Attempts to create a `TypeIdPrefixRef` from a string slice without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input string is not a valid `TypeID` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::TypeIdPrefixRef;
+    /// use std::convert::TryFrom;
+    ///
+    /// let valid = TypeIdPrefixRef::try_from("valid_prefix").unwrap();
+    /// assert_eq!(valid.as_str(), "valid_prefix");
+    ///
+    /// let invalid = TypeIdPrefixRef::try_from("Invalid_Prefix");
+    /// assert!(invalid.is_err());
+    /// ```
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::validate(input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<TypeIdPrefixRef<'_>> for TypeIdPrefix {
+    fn from(prefix: TypeIdPrefixRef<'_>) -> Self {
+        prefix.to_prefix()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a TypeIdPrefix> for TypeIdPrefixRef<'a> {
+    fn from(prefix: &'a TypeIdPrefix) -> Self {
+        prefix.as_prefix_ref()
+    }
+}
+
+impl<'a> TypeIdPrefixRef<'a> {
+    pub(crate) fn validate(input: &'a str) -> Result<Self, ValidationError> {
+        validation::check_bytes(input.as_bytes())?;
+        Ok(Self(input))
+    }
+
+    /// Attempts to borrow `input` as a prefix under the rules of a specific [`SpecVersion`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the input is not a valid prefix under `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::{SpecVersion, TypeIdPrefixRef};
+    ///
+    /// assert!(TypeIdPrefixRef::try_from_spec("user_account", SpecVersion::V0_2).is_err());
+    /// assert!(TypeIdPrefixRef::try_from_spec("user_account", SpecVersion::V0_3).is_ok());
+    /// ```
+    pub const fn try_from_spec(input: &'a str, version: SpecVersion) -> Result<Self, ValidationError> {
+        match validation::check_bytes_with(input.as_bytes(), version) {
+            Ok(()) => Ok(Self(input)),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn new_unchecked(input: &'a str) -> Self {
+        Self(input)
+    }
+
+    /// Returns the borrowed string slice of the `TypeID` prefix.
+    ///
+    /// Unlike [`Deref`], the returned slice keeps the lifetime of the original input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::TypeIdPrefixRef;
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = TypeIdPrefixRef::try_from("valid_prefix").unwrap();
+    /// assert_eq!(prefix.as_str(), "valid_prefix");
+    /// ```
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Converts the borrowed prefix into an owned [`TypeIdPrefix`].
+    ///
+    /// The input has already been validated, so this only copies the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_prefix::{TypeIdPrefix, TypeIdPrefixRef};
+    /// use std::convert::TryFrom;
+    ///
+    /// let prefix = TypeIdPrefixRef::try_from("user").unwrap();
+    /// let owned: TypeIdPrefix = prefix.to_prefix();
+    /// assert_eq!(owned.as_str(), "user");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_prefix(&self) -> TypeIdPrefix {
+        TypeIdPrefix::new_unchecked(self.0.to_string())
+    }
+}
+
+impl fmt::Display for TypeIdPrefixRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,54 @@
+use std::any::{type_name, TypeId};
+use std::collections::HashMap;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+use crate::{TypeIdPrefix, ValidationError};
+
+/// Derived prefixes are leaked once per type, so cache hits hand out a reference without
+/// allocating. Failed derivations are cached too, so each type name is converted only once.
+type Cache = RwLock<HashMap<TypeId, Result<&'static TypeIdPrefix, ValidationError>>>;
+
+static CACHE: LazyLock<Cache> = LazyLock::new(Cache::default);
+
+/// Returns the prefix derived from the name of `T`, computing it on first use.
+pub fn prefix_of<T: ?Sized + 'static>() -> Result<&'static TypeIdPrefix, ValidationError> {
+    let id = TypeId::of::<T>();
+    if let Some(&cached) = CACHE.read().unwrap_or_else(PoisonError::into_inner).get(&id) {
+        return cached;
+    }
+    *CACHE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(id)
+        .or_insert_with(|| {
+            TypeIdPrefix::validate(&snake_case(type_name::<T>()))
+                .map(|prefix| &*Box::leak(Box::new(prefix)))
+        })
+}
+
+/// Converts a type name such as `crate::model::HTTPRequest<u8>` into `http_request`.
+///
+/// The module path and generic arguments are dropped, and an underscore is inserted at each
+/// word boundary of the remaining `CamelCase` name.
+fn snake_case(type_name: &str) -> String {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    let name = without_generics.rsplit("::").next().unwrap_or(without_generics);
+
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            let starts_word = previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower);
+            if starts_word {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
@@ -0,0 +1,106 @@
+// Internal items are marked `pub(crate)`, like the crate-internal helpers on `TypeIdPrefix`
+#![allow(clippy::redundant_pub_crate)]
+
+use crate::{SpecVersion, ValidationError};
+
+/// The maximum length of a `TypeID` prefix, in bytes.
+pub(crate) const MAX_LENGTH: usize = 63;
+
+// Byte classes, combined with bitwise OR while scanning the input.
+const LOWERCASE: u8 = 0b0001;
+const UNDERSCORE: u8 = 0b0010;
+pub(crate) const OTHER_ASCII: u8 = 0b0100;
+pub(crate) const NON_ASCII: u8 = 0b1000;
+
+pub(crate) const CLASSES: [u8; 256] = build_classes();
+
+const fn build_classes() -> [u8; 256] {
+    let mut classes = [NON_ASCII; 256];
+    let mut byte = 0;
+    while byte < 128 {
+        classes[byte] = OTHER_ASCII;
+        byte += 1;
+    }
+    let mut byte = b'a';
+    while byte <= b'z' {
+        classes[byte as usize] = LOWERCASE;
+        byte += 1;
+    }
+    classes[b'_' as usize] = UNDERSCORE;
+    classes
+}
+
+/// Validates `input` in a single pass using a byte class lookup table.
+///
+/// Errors are reported with the same precedence as the original multi-pass validator:
+/// length, emptiness, non-ASCII, leading/trailing underscore, start/end character and
+/// finally any other invalid character.
+pub(crate) const fn check_bytes(input: &[u8]) -> Result<(), ValidationError> {
+    if input.len() > MAX_LENGTH {
+        return Err(ValidationError::ExceedsMaxLength);
+    }
+
+    if input.is_empty() {
+        return Err(ValidationError::IsEmpty);
+    }
+
+    let mut seen = 0;
+    let mut i = 0;
+    while i < input.len() {
+        seen |= CLASSES[input[i] as usize];
+        i += 1;
+    }
+
+    finish(input[0], input[input.len() - 1], seen)
+}
+
+/// Validates `input` under the prefix rules of `version`.
+///
+/// Underscores in a prefix that is otherwise valid are reported as
+/// [`ValidationError::ContainsInvalidCharacters`] when `version` does not allow them.
+pub(crate) const fn check_bytes_with(input: &[u8], version: SpecVersion) -> Result<(), ValidationError> {
+    if let Err(error) = check_bytes(input) {
+        return Err(error);
+    }
+
+    if !version.rules().allows_underscores {
+        let mut i = 0;
+        while i < input.len() {
+            if input[i] == b'_' {
+                return Err(ValidationError::ContainsInvalidCharacters);
+            }
+            i += 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Turns the OR of the byte classes of `input` into the first error, if any, in precedence order.
+pub(crate) const fn finish(first: u8, last: u8, seen: u8) -> Result<(), ValidationError> {
+    if seen & NON_ASCII != 0 {
+        return Err(ValidationError::ContainsInvalidCharacters);
+    }
+
+    if first == b'_' {
+        return Err(ValidationError::StartsWithUnderscore);
+    }
+
+    if last == b'_' {
+        return Err(ValidationError::EndsWithUnderscore);
+    }
+
+    if CLASSES[first as usize] != LOWERCASE {
+        return Err(ValidationError::InvalidStartCharacter);
+    }
+
+    if CLASSES[last as usize] != LOWERCASE {
+        return Err(ValidationError::InvalidEndCharacter);
+    }
+
+    if seen & OTHER_ASCII != 0 {
+        return Err(ValidationError::ContainsInvalidCharacters);
+    }
+
+    Ok(())
+}
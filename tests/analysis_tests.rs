@@ -0,0 +1,96 @@
+//! Tests for the `analysis` module.
+//!
+//! This module checks reserved-word, singular/plural and edit-distance findings for sets of
+//! `TypeIdPrefix` values.
+
+#![cfg(feature = "alloc")]
+#![doc(hidden)]
+
+use typeid_prefix::analysis::{Finding, PrefixAnalyzer};
+use typeid_prefix::TypeIdPrefix;
+
+fn prefixes(inputs: &[&str]) -> Vec<TypeIdPrefix> {
+    inputs.iter().map(|input| TypeIdPrefix::try_from(*input).unwrap()).collect()
+}
+
+#[test]
+fn test_reserved_words() {
+    let findings = PrefixAnalyzer::new().analyze(&prefixes(&["admin", "system_job", "order", "administrator"]));
+    assert_eq!(
+        findings,
+        vec![
+            Finding::Reserved {
+                prefix: TypeIdPrefix::try_from("admin").unwrap(),
+                word: "admin".into(),
+            },
+            Finding::Reserved {
+                prefix: TypeIdPrefix::try_from("system_job").unwrap(),
+                word: "system".into(),
+            },
+        ]
+    );
+
+    let findings = PrefixAnalyzer::new().reserved(["order"]).analyze(&prefixes(&["admin", "order"]));
+    assert_eq!(
+        findings,
+        vec![Finding::Reserved {
+            prefix: TypeIdPrefix::try_from("order").unwrap(),
+            word: "order".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_singular_plural_pairs() {
+    let findings = PrefixAnalyzer::new().analyze(&prefixes(&["categories", "box", "category", "boxes", "order_item", "order_items"]));
+    assert_eq!(
+        findings,
+        vec![
+            Finding::SingularPlural {
+                singular: TypeIdPrefix::try_from("category").unwrap(),
+                plural: TypeIdPrefix::try_from("categories").unwrap(),
+            },
+            Finding::SingularPlural {
+                singular: TypeIdPrefix::try_from("box").unwrap(),
+                plural: TypeIdPrefix::try_from("boxes").unwrap(),
+            },
+            Finding::SingularPlural {
+                singular: TypeIdPrefix::try_from("order_item").unwrap(),
+                plural: TypeIdPrefix::try_from("order_items").unwrap(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_similar_spelling() {
+    let findings = PrefixAnalyzer::new().analyze(&prefixes(&["user", "usr", "account", "acount", "order"]));
+    assert_eq!(
+        findings,
+        vec![
+            Finding::SimilarSpelling {
+                first: TypeIdPrefix::try_from("user").unwrap(),
+                second: TypeIdPrefix::try_from("usr").unwrap(),
+                distance: 1,
+            },
+            Finding::SimilarSpelling {
+                first: TypeIdPrefix::try_from("account").unwrap(),
+                second: TypeIdPrefix::try_from("acount").unwrap(),
+                distance: 1,
+            },
+        ]
+    );
+
+    let findings = PrefixAnalyzer::new().max_distance(2).analyze(&prefixes(&["users", "usr"]));
+    assert_eq!(findings.len(), 1);
+
+    let findings = PrefixAnalyzer::new().max_distance(0).analyze(&prefixes(&["user", "usr"]));
+    assert!(findings.is_empty());
+}
+
+#[test]
+fn test_duplicates_and_display() {
+    let findings = PrefixAnalyzer::new().analyze(&prefixes(&["user", "user", "users"]));
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].to_string(), "\"users\" is the plural of \"user\"");
+}
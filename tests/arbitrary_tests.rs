@@ -0,0 +1,70 @@
+//! Tests for the `Arbitrary` implementation of `TypeIdPrefix`.
+//!
+//! This module feeds pseudo-random byte buffers to `Arbitrary` and checks that every generated
+//! prefix is valid and that lengths and underscore placements are well spread.
+
+#![cfg(feature = "arbitrary")]
+#![doc(hidden)]
+
+use std::collections::HashSet;
+
+use arbitrary::{Arbitrary, Unstructured};
+use typeid_prefix::TypeIdPrefix;
+
+/// A small xorshift generator, so the test is deterministic without extra dependencies.
+fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state.to_le_bytes()[0]
+        })
+        .collect()
+}
+
+#[test]
+fn test_arbitrary_prefixes_are_valid() {
+    let mut lengths = HashSet::new();
+    let mut with_underscores = 0;
+    let mut with_double_underscores = 0;
+
+    for seed in 0..2000 {
+        let bytes = random_bytes(seed, 256);
+        let prefix = TypeIdPrefix::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(TypeIdPrefix::try_from(prefix.as_str()).is_ok(), "{prefix:?}");
+
+        lengths.insert(prefix.len());
+        with_underscores += usize::from(prefix.contains('_'));
+        with_double_underscores += usize::from(prefix.contains("__"));
+    }
+
+    assert_eq!(lengths.len(), 63, "every length from 1 to 63 should be generated");
+    assert!(with_underscores > 1000);
+    assert!(with_double_underscores > 100);
+}
+
+#[test]
+fn test_arbitrary_with_little_data() {
+    for len in 0..8 {
+        let bytes = random_bytes(42, len);
+        let prefix = TypeIdPrefix::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(TypeIdPrefix::try_from(prefix.as_str()).is_ok(), "{prefix:?}");
+    }
+}
+
+#[test]
+fn test_size_hint_covers_consumed_bytes() {
+    let (lower, upper) = TypeIdPrefix::size_hint(0);
+    let upper = upper.unwrap();
+    assert_eq!(lower, 0, "an empty Unstructured still yields a prefix");
+
+    for seed in 0..500 {
+        let bytes = random_bytes(seed, 512);
+        let mut u = Unstructured::new(&bytes);
+        TypeIdPrefix::arbitrary(&mut u).unwrap();
+        let consumed = bytes.len() - u.len();
+        assert!((lower..=upper).contains(&consumed), "consumed {consumed} bytes");
+    }
+}
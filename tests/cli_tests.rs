@@ -0,0 +1,205 @@
+//! Tests for the `typeid-prefix` command-line tool.
+//!
+//! This module runs the compiled binary and checks its output and exit status
+//! for the prefix subcommands, the `new`, `decode` and `encode` `TypeID` subcommands and
+//! the `lint` subcommand.
+
+#![cfg(feature = "cli")]
+#![doc(hidden)]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_typeid-prefix"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut child_stdin = child.stdin.take().unwrap();
+    child_stdin.write_all(stdin.unwrap_or_default().as_bytes()).unwrap();
+    drop(child_stdin);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_validate_all_valid() {
+    let output = run(&["validate", "user", "order_item"], None);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "\"user\": valid\n\"order_item\": valid\n");
+}
+
+#[test]
+fn test_validate_invalid_exits_nonzero() {
+    let output = run(&["validate", "user", "Invalid_Prefix"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("\"Invalid_Prefix\": invalid (InvalidStartCharacter)"));
+}
+
+#[test]
+fn test_validate_reads_stdin() {
+    let output = run(&["validate"], Some("user\n_user\n"));
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "\"user\": valid\n\"_user\": invalid (StartsWithUnderscore): Input cannot start with an underscore\n"
+    );
+}
+
+#[test]
+fn test_validate_json() {
+    let output = run(&["--format", "json", "validate", "-"], Some("user\n\n"));
+    let lines: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["valid"], true);
+    assert_eq!(lines[1]["valid"], false);
+    assert_eq!(lines[1]["error"]["variant"], "IsEmpty");
+    assert_eq!(lines[1]["error"]["message"], "Input cannot be empty");
+}
+
+#[test]
+fn test_sanitize() {
+    let output = run(&["sanitize", "User Account!"], None);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "\"User Account!\" -> useraccount\n");
+
+    let output = run(&["sanitize", "-f", "json", "123"], None);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["sanitized"], "");
+    assert_eq!(report["error"]["variant"], "IsEmpty");
+}
+
+#[test]
+fn test_explain() {
+    let output = run(&["explain", "-f", "json", "user_"], None);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["error"]["variant"], "EndsWithUnderscore");
+    let failed: Vec<&str> = report["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|rule| rule["passed"] == false)
+        .map(|rule| rule["rule"].as_str().unwrap())
+        .collect();
+    assert_eq!(failed, ["does not end with an underscore", "ends with a lowercase letter"]);
+}
+
+#[test]
+fn test_new() {
+    let output = run(&["-f", "json", "new", "user"], None);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["prefix"], "user");
+    assert!(report["typeid"].as_str().unwrap().starts_with("user_0"));
+    assert_eq!(&report["uuid"].as_str().unwrap()[14..15], "7");
+    assert!(report["timestamp"].as_str().unwrap().ends_with('Z'));
+
+    let output = run(&["new", "User"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("invalid prefix (InvalidStartCharacter)"));
+}
+
+#[test]
+fn test_decode() {
+    let output = run(&["-f", "json", "decode", "user_01h455vb4pex5vsknk084sn02q"], None);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["prefix"], "user");
+    assert_eq!(report["uuid"], "01890a5d-ac96-774b-bcce-b302099a8057");
+    assert_eq!(report["timestamp"], "2023-06-30T03:34:18.518Z");
+}
+
+#[test]
+fn test_decode_reports_failing_half() {
+    let output = run(&["-f", "json", "decode"], Some("User_01h455vb4pex5vsknk084sn02q\nuser_01h455vb4pex5vsknk084sn02\n"));
+    assert_eq!(output.status.code(), Some(1));
+    let reports: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(reports[0]["error"]["part"], "prefix");
+    assert_eq!(reports[0]["error"]["variant"], "InvalidStartCharacter");
+    assert_eq!(reports[1]["error"]["part"], "suffix");
+    assert_eq!(reports[1]["error"]["variant"], "InvalidLength");
+}
+
+#[test]
+fn test_encode() {
+    let output = run(&["encode", "user", "01890a5d-ac96-774b-bcce-b302099a8057"], None);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("typeid:    user_01h455vb4pex5vsknk084sn02q\n"));
+
+    let output = run(&["encode", "user_", "01890a5d-ac96-774b-bcce-b302099a8057"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("invalid prefix (EndsWithUnderscore)"));
+}
+
+#[test]
+fn test_lint_directory() {
+    let dir = std::env::temp_dir().join(format!("typeid-prefix-lint-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::create_dir_all(dir.join("target")).unwrap();
+    std::fs::write(dir.join("src/lib.rs"), "let p = TypeIdPrefix::try_from(\"User\");\n").unwrap();
+    std::fs::write(dir.join("target/skipped.rs"), "let p = TypeIdPrefix::try_from(\"User\");\n").unwrap();
+
+    let output = run(&["-f", "json", "lint", dir.to_str().unwrap()], None);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let reports: Vec<serde_json::Value> = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0]["path"].as_str().unwrap().ends_with("lib.rs"));
+    assert_eq!(reports[0]["line"], 1);
+    assert_eq!(reports[0]["column"], 32);
+    assert_eq!(reports[0]["error"]["variant"], "InvalidStartCharacter");
+}
+
+#[test]
+fn test_lint_clean_file() {
+    let output = run(&["lint", "src/validation.rs"], None);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn test_lint_reports_unreadable_files_and_continues() {
+    let dir = std::env::temp_dir().join(format!("typeid-prefix-lint-unreadable-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("binary.rs"), [0xff, 0xfe, 0x00]).unwrap();
+    std::fs::write(dir.join("lib.rs"), "let p = TypeIdPrefix::try_from(\"User\");\n").unwrap();
+
+    let output = run(&["lint", dir.to_str().unwrap()], None);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("binary.rs"), "{stderr}");
+    assert!(stdout(&output).contains("lib.rs:1:32: TypeIdPrefix::try_from(\"User\")"));
+}
+
+#[test]
+fn test_new_and_encode_without_prefix() {
+    let output = run(&["encode", "", "01890a5d-ac96-774b-bcce-b302099a8057"], None);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("typeid:    01h455vb4pex5vsknk084sn02q\n"));
+
+    let output = run(&["-f", "json", "new", ""], None);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_str(stdout(&output).trim()).unwrap();
+    assert_eq!(report["prefix"], "");
+    assert_eq!(report["typeid"].as_str().unwrap().len(), 26);
+}
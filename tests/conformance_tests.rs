@@ -0,0 +1,74 @@
+//! Conformance tests against the `TypeID` specification's fixture files.
+//!
+//! The fixtures in `tests/fixtures` are vendored from the specification. Every case whose
+//! prefix is checked by the spec is run through `TypeIdPrefix` and compared with the cases
+//! exposed by the `conformance` module, so the two cannot drift apart.
+
+#![cfg(feature = "alloc")]
+#![doc(hidden)]
+
+use serde::Deserialize;
+use typeid_prefix::conformance::{self, INVALID, VALID};
+use typeid_prefix::{TypeIdPrefix, TypeIdPrefixRef};
+
+#[derive(Debug, Deserialize)]
+struct Fixture {
+    name: String,
+    typeid: String,
+    #[serde(default)]
+    prefix: Option<String>,
+}
+
+fn load(file: &str) -> Vec<Fixture> {
+    let path = format!("{}/tests/fixtures/{file}", env!("CARGO_MANIFEST_DIR"));
+    serde_yaml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Returns the text before the last separator, as a `TypeID` parser would see it.
+fn prefix_of(typeid: &str) -> Option<&str> {
+    typeid.rsplit_once('_').map(|(prefix, _)| prefix)
+}
+
+#[test]
+fn test_valid_fixtures() {
+    let fixtures = load("valid.yml");
+    let with_prefix: Vec<_> = fixtures.iter().filter(|f| f.prefix.as_deref() != Some("")).collect();
+    assert_eq!(with_prefix.len(), VALID.len());
+
+    for fixture in with_prefix {
+        let prefix = fixture.prefix.as_deref().unwrap();
+        assert_eq!(prefix_of(&fixture.typeid), Some(prefix), "{}", fixture.name);
+        assert!(TypeIdPrefix::try_from(prefix).is_ok(), "{} should be valid", fixture.name);
+
+        let case = VALID.iter().find(|case| case.name == fixture.name).unwrap();
+        assert_eq!(case.prefix, prefix);
+    }
+}
+
+#[test]
+fn test_invalid_fixtures() {
+    let fixtures = load("invalid.yml");
+    let prefix_cases: Vec<_> = fixtures
+        .iter()
+        .filter(|f| f.name.starts_with("prefix-") || f.name.starts_with("separator-"))
+        .collect();
+    assert_eq!(prefix_cases.len(), INVALID.len());
+
+    for fixture in prefix_cases {
+        let prefix = prefix_of(&fixture.typeid).unwrap();
+        let case = INVALID.iter().find(|case| case.name == fixture.name).unwrap();
+        assert_eq!(case.prefix, prefix, "{}", fixture.name);
+        assert_eq!(TypeIdPrefix::try_from(prefix), Err(case.error), "{}", fixture.name);
+    }
+}
+
+#[test]
+fn test_conformance_cases() {
+    let owned: Vec<_> = conformance::mismatches(|prefix| TypeIdPrefix::try_from(prefix).is_ok()).collect();
+    assert!(owned.is_empty(), "{owned:?}");
+
+    let borrowed: Vec<_> = conformance::mismatches(|prefix| TypeIdPrefixRef::try_from(prefix).is_ok()).collect();
+    assert!(borrowed.is_empty(), "{borrowed:?}");
+
+    assert_eq!(conformance::mismatches(|_| true).count(), INVALID.len());
+}
@@ -0,0 +1,58 @@
+//! Tests for the async-graphql scalar implementation of `TypeIdPrefix`.
+//!
+//! This module tests that `TypeIdPrefix` parses GraphQL input values using the
+//! `TypeID` specification rules and reports validation failures as input errors.
+
+#![cfg(feature = "async-graphql")]
+#![doc(hidden)]
+
+use std::convert::TryFrom;
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Pos, ScalarType, Schema, Value};
+use typeid_prefix::{TypeIdPrefix, ValidationError};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn echo(&self, prefix: TypeIdPrefix) -> TypeIdPrefix {
+        prefix
+    }
+}
+
+#[test]
+fn test_parse_valid_typeidprefix() {
+    let prefix = <TypeIdPrefix as ScalarType>::parse(Value::String("valid_prefix".to_string())).unwrap();
+    assert_eq!(prefix.as_str(), "valid_prefix");
+}
+
+#[test]
+fn test_parse_invalid_typeidprefix() {
+    // The validation error message is surfaced as the input error message
+    let err = <TypeIdPrefix as ScalarType>::parse(Value::String("Invalid_Prefix".to_string())).unwrap_err();
+    let message = err.into_server_error(Pos::default()).message;
+    assert!(
+        message.contains(&ValidationError::InvalidStartCharacter.to_string()),
+        "Unexpected message: {message}"
+    );
+}
+
+#[test]
+fn test_parse_non_string_value() {
+    assert!(<TypeIdPrefix as ScalarType>::parse(Value::Number(42.into())).is_err());
+    assert!(!<TypeIdPrefix as ScalarType>::is_valid(&Value::Boolean(true)));
+}
+
+#[test]
+fn test_to_value() {
+    let prefix = TypeIdPrefix::try_from("user").unwrap();
+    assert_eq!(prefix.to_value(), Value::String("user".to_string()));
+}
+
+#[test]
+fn test_schema_publishes_scalar_description() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains("scalar TypeIdPrefix"), "Missing scalar in SDL: {sdl}");
+    assert!(sdl.contains("lowercase ASCII letters and underscores"), "Missing description in SDL: {sdl}");
+}
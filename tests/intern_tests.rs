@@ -0,0 +1,83 @@
+//! Tests for interned prefixes.
+//!
+//! This module tests that interning stores each distinct prefix once, that handles compare
+//! by identity, and that conversions to and from `TypeIdPrefix` are lossless.
+
+#![cfg(feature = "intern")]
+#![doc(hidden)]
+
+use std::collections::HashSet;
+use std::thread;
+
+use typeid_prefix::intern::{InternedPrefix, Interner};
+use typeid_prefix::prelude::*;
+
+#[test]
+fn test_interning_stores_each_prefix_once() {
+    let interner = Interner::global();
+    assert_eq!(interner.get("stored_once"), None);
+
+    let first = interner.try_intern("stored_once").unwrap();
+    let second = interner.intern(&TypeIdPrefix::try_from("stored_once").unwrap());
+    let other = interner.try_intern("stored_other").unwrap();
+
+    assert_eq!(first, second);
+    assert!(std::ptr::eq(first.as_prefix(), second.as_prefix()));
+    assert_ne!(first, other);
+    assert!(!interner.is_empty());
+    assert_eq!(interner.get("stored_once"), Some(first));
+    assert_eq!(interner.get("never_interned"), None);
+}
+
+#[test]
+fn test_interning_rejects_invalid_input() {
+    let interner = Interner::global();
+    assert_eq!(interner.try_intern("_user"), Err(ValidationError::StartsWithUnderscore));
+    assert_eq!(InternedPrefix::try_from("User"), Err(ValidationError::InvalidStartCharacter));
+    assert_eq!(interner.get("_user"), None);
+    assert_eq!(interner.get("User"), None);
+}
+
+#[test]
+fn test_conversions_share_the_global_interner() {
+    let interned = Interner::global().try_intern("user").unwrap();
+    assert_eq!(InternedPrefix::try_from("user").unwrap(), interned);
+    assert_eq!(InternedPrefix::from(TypeIdPrefix::try_from("user").unwrap()), interned);
+    assert_eq!(Interner::global().get("user"), Some(interned));
+}
+
+#[test]
+fn test_conversions_are_lossless() {
+    let owned = TypeIdPrefix::try_from("order_item").unwrap();
+    let interned = InternedPrefix::from(&owned);
+    assert_eq!(interned, owned);
+    assert_eq!(TypeIdPrefix::from(interned), owned);
+    assert_eq!(interned.to_string(), "order_item");
+    assert_eq!(&*interned, "order_item");
+    assert_eq!(format!("{interned:?}"), "InternedPrefix(\"order_item\")");
+    assert_eq!(InternedPrefix::from(owned), interned);
+}
+
+#[test]
+fn test_hashing_is_consistent_with_equality() {
+    let interner = Interner::global();
+    let set: HashSet<InternedPrefix> = ["user", "order", "user", "order", "item"]
+        .into_iter()
+        .map(|p| interner.try_intern(p).unwrap())
+        .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&interner.get("item").unwrap()));
+}
+
+#[test]
+fn test_concurrent_interning_returns_one_handle() {
+    let handles: Vec<InternedPrefix> = thread::scope(|scope| {
+        // Spawn every worker before joining any of them so they race on the table
+        #[allow(clippy::needless_collect)]
+        let workers: Vec<_> = (0..8)
+            .map(|_| scope.spawn(|| InternedPrefix::try_from("concurrent_prefix").unwrap()))
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+    assert!(handles.windows(2).all(|pair| pair[0] == pair[1]));
+}
@@ -0,0 +1,174 @@
+//! Tests for the source-code linter.
+//!
+//! This module tests that `lint_source` finds invalid prefix literals at the
+//! supported call sites and ignores literals in comments and other contexts.
+
+#![cfg(feature = "lint")]
+#![doc(hidden)]
+
+use typeid_prefix::lint::{lint_source, LintCall, MalformedLiteral};
+use typeid_prefix::ValidationError;
+
+#[test]
+fn test_reports_invalid_try_from_literals() {
+    let source = r#"
+fn main() {
+    let ok = TypeIdPrefix::try_from("user").unwrap();
+    let bad = TypeIdPrefix::try_from( "User" ).unwrap();
+    let qualified = typeid_prefix::TypeIdPrefix::from_str("_user").unwrap();
+}
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 2);
+
+    assert_eq!((issues[0].line, issues[0].column), (4, 39));
+    assert_eq!(issues[0].call, LintCall::TryFrom);
+    assert_eq!(issues[0].literal, "User");
+    assert_eq!(issues[0].error, Some(ValidationError::InvalidStartCharacter));
+    assert_eq!(
+        issues[0].to_string(),
+        "4:39: TypeIdPrefix::try_from(\"User\") would fail with InvalidStartCharacter: \
+         Input must start with a lowercase alphabetic character"
+    );
+
+    assert_eq!(issues[1].line, 5);
+    assert_eq!(issues[1].call, LintCall::FromStr);
+    assert_eq!(issues[1].error, Some(ValidationError::StartsWithUnderscore));
+}
+
+#[test]
+fn test_reports_sanitized_literals_that_differ() {
+    let source = r#"
+let unchanged = "user".create_prefix_sanitized();
+let changed = "Order Item"
+    .create_prefix_sanitized();
+let empty = "123".create_prefix_sanitized();
+let checked = "user_".try_create_prefix();
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].call, LintCall::CreatePrefixSanitized);
+    assert_eq!(issues[0].error, None);
+    assert_eq!(issues[0].sanitized.as_deref(), Some("orderitem"));
+    assert_eq!(issues[1].sanitized.as_deref(), Some(""));
+    assert_eq!(issues[2].call, LintCall::TryCreatePrefix);
+    assert_eq!(issues[2].error, Some(ValidationError::EndsWithUnderscore));
+}
+
+#[test]
+fn test_resolves_escapes_and_raw_strings() {
+    let source = r##"
+let escaped = TypeIdPrefix::try_from("us\u{65}r\x5f").unwrap();
+let raw = TypeIdPrefix::try_from(r#"Raw"#).unwrap();
+let continued = TypeIdPrefix::try_from("user\
+    _id").unwrap();
+"##;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].literal, "user_");
+    assert_eq!(issues[0].error, Some(ValidationError::EndsWithUnderscore));
+    assert_eq!(issues[1].literal, "Raw");
+}
+
+#[test]
+fn test_ignores_comments_chars_and_other_calls() {
+    let source = r#"
+// TypeIdPrefix::try_from("Commented").unwrap();
+/* TypeIdPrefix::try_from("Block /* nested */ comment") */
+fn quote<'a>(c: char) -> bool { c == '"' || c == '\'' }
+let other = Other::try_from("User");
+let two_args = TypeIdPrefix::try_from("User", 1);
+let bytes = TypeIdPrefix::try_from(b"User");
+let formatted = format!("{}", "User").len();
+let after_char = TypeIdPrefix::try_from("Bad");
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].literal, "Bad");
+    assert_eq!(issues[0].line, 9);
+}
+
+#[test]
+fn test_accepts_trailing_comma_in_formatted_calls() {
+    let source = r#"
+let formatted = TypeIdPrefix::try_from(
+    "User",
+)
+.unwrap();
+let ok = TypeIdPrefix::from_str("user" ,);
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!((issues[0].line, issues[0].call), (3, LintCall::TryFrom));
+    assert_eq!(issues[0].literal, "User");
+}
+
+#[test]
+fn test_reports_malformed_literals() {
+    let source = r#"
+let escape = TypeIdPrefix::try_from("us\qer").unwrap();
+let ignored = Other::new("\q");
+let sanitized = "\q".create_prefix_sanitized();
+let unterminated = TypeIdPrefix::try_from("user
+    ).unwrap();
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 3, "{issues:?}");
+
+    assert_eq!(issues[0].call, LintCall::TryFrom);
+    assert_eq!(issues[0].literal, "us\\qer");
+    assert_eq!(issues[0].malformed, Some(MalformedLiteral::InvalidEscape));
+    assert_eq!(issues[0].error, None);
+    assert_eq!(
+        issues[0].to_string(),
+        "2:37: TypeIdPrefix::try_from(\"us\\qer\") contains an invalid escape"
+    );
+
+    assert_eq!(issues[1].call, LintCall::CreatePrefixSanitized);
+    assert_eq!(issues[1].malformed, Some(MalformedLiteral::InvalidEscape));
+
+    assert_eq!(issues[2].line, 5);
+    assert_eq!(issues[2].literal, "user");
+    assert_eq!(issues[2].malformed, Some(MalformedLiteral::Unterminated));
+}
+
+#[test]
+fn test_rejects_non_ascii_and_signed_hex_escapes() {
+    let source = r#"
+let ascii = TypeIdPrefix::try_from("user\x5f").unwrap();
+let high = TypeIdPrefix::try_from("user\x80").unwrap();
+let signed = TypeIdPrefix::try_from("user\x+f").unwrap();
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 3, "{issues:?}");
+    assert_eq!(issues[0].error, Some(ValidationError::EndsWithUnderscore));
+    assert_eq!(issues[1].malformed, Some(MalformedLiteral::InvalidEscape));
+    assert_eq!(issues[2].malformed, Some(MalformedLiteral::InvalidEscape));
+}
+
+#[test]
+fn test_unicode_escapes_need_braces_and_allow_separators() {
+    let source = r#"
+let separated = TypeIdPrefix::try_from("user\u{1_F600}").unwrap();
+let unbraced = TypeIdPrefix::try_from("user\u0041").unwrap();
+let runaway = TypeIdPrefix::try_from("user\u{41").unwrap(); let x = "}";
+let too_long = TypeIdPrefix::try_from("user\u{0000041}").unwrap();
+"#;
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 4, "{issues:?}");
+    assert_eq!(issues[0].literal, "user\u{1F600}");
+    assert_eq!(issues[0].error, Some(ValidationError::ContainsInvalidCharacters));
+    for issue in &issues[1..] {
+        assert_eq!(issue.malformed, Some(MalformedLiteral::InvalidEscape), "{issue}");
+    }
+}
+
+#[test]
+fn test_accepts_crlf_line_continuations() {
+    let source = "let continued = TypeIdPrefix::try_from(\"User\\\r\n    _id\").unwrap();\r\n";
+    let issues = lint_source(source);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].malformed, None);
+    assert_eq!(issues[0].literal, "User_id");
+    assert_eq!(issues[0].error, Some(ValidationError::InvalidStartCharacter));
+}
@@ -0,0 +1,68 @@
+//! Tests for `PrefixPolicy`.
+//!
+//! This module checks that the specification rules run before the custom rules and that
+//! each custom rule reports its own `PolicyError` variant.
+
+#![cfg(feature = "alloc")]
+#![doc(hidden)]
+
+use std::error::Error;
+
+use typeid_prefix::policy::{PolicyError, PrefixPolicy};
+use typeid_prefix::{SpecVersion, TypeIdPrefix, ValidationError};
+
+fn house_rules() -> PrefixPolicy {
+    PrefixPolicy::new()
+        .min_length(3)
+        .forbid_double_underscores()
+        .max_segments(3)
+        .deny(["admin", "system"])
+}
+
+#[test]
+fn test_default_policy_matches_spec() {
+    let policy = PrefixPolicy::new();
+    for input in ["a", "user", "a__b", "a_b_c_d_e", "admin", "Invalid", "_user", ""] {
+        assert_eq!(
+            policy.validate(input),
+            TypeIdPrefix::try_from(input).map_err(PolicyError::Spec),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn test_house_rules() {
+    let policy = house_rules();
+    assert_eq!(policy.validate("abc").unwrap().as_str(), "abc");
+    assert_eq!(policy.validate("order_line_item").unwrap().as_str(), "order_line_item");
+    assert_eq!(policy.validate("ab"), Err(PolicyError::TooShort { min_length: 3 }));
+    assert_eq!(policy.validate("order__item"), Err(PolicyError::DoubleUnderscore));
+    assert_eq!(policy.validate("a_b_c_d"), Err(PolicyError::TooManySegments { max_segments: 3 }));
+    assert_eq!(policy.validate("system"), Err(PolicyError::Denied { word: "system".to_string() }));
+    assert!(policy.validate("admin_user").is_ok());
+}
+
+#[test]
+fn test_spec_rules_run_first() {
+    let policy = house_rules();
+    assert_eq!(policy.validate("Ab"), Err(PolicyError::Spec(ValidationError::InvalidStartCharacter)));
+    assert_eq!(policy.validate("_admin"), Err(PolicyError::Spec(ValidationError::StartsWithUnderscore)));
+
+    let legacy = house_rules().spec_version(SpecVersion::V0_2);
+    assert_eq!(
+        legacy.validate("order_item"),
+        Err(PolicyError::Spec(ValidationError::ContainsInvalidCharacters))
+    );
+}
+
+#[test]
+fn test_policy_error_source() {
+    let error = house_rules().validate("Admin").unwrap_err();
+    let source = error.source().unwrap().downcast_ref::<ValidationError>();
+    assert_eq!(source, Some(&ValidationError::InvalidStartCharacter));
+    let denied = PolicyError::Denied { word: "admin".to_string() };
+    assert!(denied.source().is_none());
+    assert_eq!(denied.to_string(), "Prefix 'admin' is a reserved word");
+    assert_eq!(PolicyError::TooShort { min_length: 3 }.to_string(), "Prefix must be at least 3 characters");
+}
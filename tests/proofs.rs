@@ -1,109 +1,173 @@
+//! Kani proof harnesses for prefix validation and sanitization.
+//!
+//! Run them with `cargo kani --tests`. Each property is its own harness, so a failing
+//! property can be checked in isolation with `cargo kani --tests --harness <name>`.
+//! Contents are fully symbolic and lengths are bounded so that every harness terminates.
+//! Most harnesses draw ASCII input. The sanitization properties are also checked over
+//! arbitrary UTF-8 up to `UTF8_BOUND` bytes, which covers the non-ASCII `to_lowercase` path.
+//! Truncation is only proven for ASCII input, since reaching it takes more than 63 bytes.
+
+#![cfg(feature = "alloc")]
 #![doc(hidden)]
 
 #[cfg(kani)]
 mod verification {
     use std::convert::TryFrom;
-    use typeid_prefix::{TypeIdPrefix, PrefixFactory};
-    use kani::Arbitrary;
+    use typeid_prefix::prelude::*;
 
-    #[derive(Debug)]
-    struct TypeIdPrefixInput {
-        input: String,
+    /// The maximum length of a valid prefix.
+    const MAX_LENGTH: usize = 63;
+
+    /// The length bound for harnesses that inspect every byte of the input.
+    const BOUND: usize = 8;
+
+    /// The length bound, in bytes, for harnesses over arbitrary UTF-8. Unicode case mapping
+    /// is far more expensive to explore than ASCII, so this is kept small.
+    const UTF8_BOUND: usize = 4;
+
+    /// Returns a symbolic byte string of at most `N` bytes.
+    fn any_bytes<const N: usize>() -> ([u8; N], usize) {
+        let bytes: [u8; N] = kani::any();
+        let len: usize = kani::any();
+        kani::assume(len <= N);
+        (bytes, len)
     }
 
-    impl Arbitrary for TypeIdPrefixInput {
-        fn any() -> Self {
-            // Generate a random length for the string (between 0 and 100 to test beyond the 63 character limit)
-            let len: usize = kani::any();
-            kani::assume(len <= 100);
-
-            // Generate arbitrary ASCII characters (including non-lowercase and non-underscore)
-            let input: String = (0..len)
-                .map(|_| {
-                    let c: u8 = kani::any();
-                    kani::assume(c.is_ascii());
-                    c as char
-                })
-                .collect();
-            TypeIdPrefixInput { input }
-        }
+    /// Returns a symbolic ASCII string of at most `N` characters.
+    fn any_ascii<const N: usize>() -> String {
+        let (bytes, len) = any_bytes::<N>();
+        kani::assume(bytes[..len].is_ascii());
+        bytes[..len].iter().copied().map(char::from).collect()
     }
 
-    #[kani::proof]
-    fn verify_typeidprefix_try_from_and_sanitize() {
-        let input: TypeIdPrefixInput = kani::any();
-        let try_from_result = TypeIdPrefix::try_from(input.input.clone());
-        let sanitized = input.input.create_prefix_sanitized();
-
-        // Verify try_from behavior
-        if input.input.len() > 63 {
-            kani::assert(try_from_result.is_err(), "Input length exceeds 63 characters, should be error.");
-        } else if input.input.is_empty() {
-            kani::assert(try_from_result.is_ok(), "Empty input should be ok.");
-        } else {
-            let is_ascii = input.input.is_ascii();
-            let starts_with_valid_char = input.input.chars().next().map_or(false, |c| c.is_ascii_lowercase());
-            let ends_with_valid_char = input.input.chars().last().map_or(false, |c| c.is_ascii_lowercase());
-            let contains_only_valid_chars = input.input.chars().all(|c| c.is_ascii_lowercase() || c == '_');
-
-            if !is_ascii || !starts_with_valid_char || !ends_with_valid_char || !contains_only_valid_chars {
-                kani::assert(try_from_result.is_err(), "Invalid input should be error.");
-            } else {
-                kani::assert(try_from_result.is_ok(), "Valid input should be ok.");
+    /// Returns a symbolic UTF-8 string of at most `N` bytes, including non-ASCII characters.
+    fn any_utf8<const N: usize>() -> String {
+        let (bytes, len) = any_bytes::<N>();
+        let input = core::str::from_utf8(&bytes[..len]);
+        kani::assume(input.is_ok());
+        input.unwrap().to_owned()
+    }
+
+    /// The prefix language from the `TypeID` specification: `[a-z]([a-z_]{0,61}[a-z])?`.
+    fn in_spec_language(input: &[u8]) -> bool {
+        match (input.first(), input.last()) {
+            (Some(first), Some(last)) => {
+                input.len() <= MAX_LENGTH
+                    && first.is_ascii_lowercase()
+                    && last.is_ascii_lowercase()
+                    && input.iter().all(|&b| b.is_ascii_lowercase() || b == b'_')
             }
+            _ => false,
         }
+    }
 
-        // Verify sanitized output
-        kani::assert(sanitized.len() <= 63, "Sanitized output should not exceed 63 characters.");
-        kani::assert(sanitized.chars().all(|c| c.is_ascii_lowercase() || c == '_'), "Sanitized output should only contain lowercase ASCII or underscore.");
-        kani::assert(!sanitized.starts_with('_'), "Sanitized output should not start with underscore.");
-        kani::assert(!sanitized.ends_with('_'), "Sanitized output should not end with underscore.");
+    #[kani::proof]
+    fn verify_rejects_inputs_longer_than_max_length() {
+        let (bytes, len) = any_bytes::<{ MAX_LENGTH + 2 }>();
+        kani::assume(len > MAX_LENGTH);
+        kani::assert(
+            TypeIdPrefix::try_from_bytes(&bytes[..len]) == Err(ValidationError::ExceedsMaxLength),
+            "Input longer than 63 bytes should be rejected as too long.",
+        );
+    }
 
-        // Ensure sanitized version is always valid
-        kani::assert(TypeIdPrefix::try_from(sanitized.as_str()).is_ok(), "Sanitized output should always be valid.");
+    #[kani::proof]
+    fn verify_rejects_empty_input() {
+        kani::assert(
+            TypeIdPrefix::try_from("") == Err(ValidationError::IsEmpty),
+            "Empty input should be rejected as empty.",
+        );
+    }
 
-        // If the original input was valid, ensure it matches the sanitized version
-        if try_from_result.is_ok() {
-            kani::assert(input.input == sanitized, "Valid input should match its sanitized version.");
-        }
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn verify_validate_accepts_exactly_spec_language() {
+        let (bytes, len) = any_bytes::<BOUND>();
+        let input = &bytes[..len];
+        kani::assert(
+            TypeIdPrefix::try_from_bytes(input).is_ok() == in_spec_language(input),
+            "Validation should accept exactly the prefixes in the spec language.",
+        );
     }
 
     #[kani::proof]
-    fn verify_typeidprefix_try_from_str_and_sanitize() {
-        let input: TypeIdPrefixInput = kani::any();
-        let try_from_result = TypeIdPrefix::try_from(input.input.as_str());
-        let sanitized = input.input.create_prefix_sanitized();
-
-        // Verify try_from behavior (same as in the previous proof)
-        if input.input.len() > 63 {
-            kani::assert(try_from_result.is_err(), "Input length exceeds 63 characters, should be error.");
-        } else if input.input.is_empty() {
-            kani::assert(try_from_result.is_ok(), "Empty input should be ok.");
-        } else {
-            let is_ascii = input.input.is_ascii();
-            let starts_with_valid_char = input.input.chars().next().map_or(false, |c| c.is_ascii_lowercase());
-            let ends_with_valid_char = input.input.chars().last().map_or(false, |c| c.is_ascii_lowercase());
-            let contains_only_valid_chars = input.input.chars().all(|c| c.is_ascii_lowercase() || c == '_');
-
-            if !is_ascii || !starts_with_valid_char || !ends_with_valid_char || !contains_only_valid_chars {
-                kani::assert(try_from_result.is_err(), "Invalid input should be error.");
-            } else {
-                kani::assert(try_from_result.is_ok(), "Valid input should be ok.");
-            }
-        }
+    #[kani::unwind(10)]
+    fn verify_str_and_bytes_validation_agree() {
+        let input = any_ascii::<BOUND>();
+        kani::assert(
+            TypeIdPrefix::try_from(input.as_str()) == TypeIdPrefix::try_from_bytes(input.as_bytes()),
+            "Validating a string and its bytes should give the same result.",
+        );
+    }
+
+    /// `clean_inner` output is valid or empty, for ASCII input up to `BOUND` bytes.
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn verify_clean_inner_output_is_valid_or_empty() {
+        let input = any_ascii::<BOUND>();
+        let cleaned = TypeIdPrefix::clean_inner_for_proofs(&input, SpecVersion::LATEST);
+        kani::assert(
+            cleaned.is_empty() || TypeIdPrefix::check(&cleaned).is_ok(),
+            "clean_inner output should be a valid prefix or empty.",
+        );
+    }
 
-        // Verify sanitized output (same as in the previous proof)
-        kani::assert(sanitized.len() <= 63, "Sanitized output should not exceed 63 characters.");
-        kani::assert(sanitized.chars().all(|c| c.is_ascii_lowercase() || c == '_'), "Sanitized output should only contain lowercase ASCII or underscore.");
-        kani::assert(!sanitized.starts_with('_'), "Sanitized output should not start with underscore.");
-        kani::assert(!sanitized.ends_with('_'), "Sanitized output should not end with underscore.");
+    /// `clean_inner` output is valid or empty, for any UTF-8 input up to `UTF8_BOUND` bytes.
+    #[kani::proof]
+    #[kani::unwind(16)]
+    fn verify_clean_inner_output_is_valid_or_empty_for_utf8() {
+        let input = any_utf8::<UTF8_BOUND>();
+        let cleaned = TypeIdPrefix::clean_inner_for_proofs(&input, SpecVersion::LATEST);
+        kani::assert(
+            cleaned.is_empty() || TypeIdPrefix::check(&cleaned).is_ok(),
+            "clean_inner output should be a valid prefix or empty for non-ASCII input.",
+        );
+    }
 
-        // Ensure sanitized version is always valid
-        kani::assert(TypeIdPrefix::try_from(sanitized.as_str()).is_ok(), "Sanitized output should always be valid.");
+    /// Sanitization is idempotent, for ASCII input up to `BOUND` bytes.
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn verify_sanitization_is_idempotent() {
+        let input = any_ascii::<BOUND>();
+        let sanitized = input.create_prefix_sanitized();
+        kani::assert(
+            sanitized.as_str().create_prefix_sanitized() == sanitized,
+            "Sanitizing sanitized output should leave it unchanged.",
+        );
+    }
 
-        // If the original input was valid, ensure it matches the sanitized version
-        if try_from_result.is_ok() {
-            kani::assert(input.input == sanitized, "Valid input should match its sanitized version.");
+    /// Sanitization is idempotent, for any UTF-8 input up to `UTF8_BOUND` bytes.
+    #[kani::proof]
+    #[kani::unwind(16)]
+    fn verify_sanitization_is_idempotent_for_utf8() {
+        let input = any_utf8::<UTF8_BOUND>();
+        let sanitized = input.create_prefix_sanitized();
+        kani::assert(
+            sanitized.as_str().create_prefix_sanitized() == sanitized,
+            "Sanitizing sanitized non-ASCII output should leave it unchanged.",
+        );
+    }
+
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn verify_sanitization_preserves_valid_input() {
+        let input = any_ascii::<BOUND>();
+        if let Ok(prefix) = TypeIdPrefix::try_from(input.as_str()) {
+            kani::assert(
+                input.create_prefix_sanitized() == prefix,
+                "Valid input should match its sanitized version.",
+            );
         }
     }
-}
\ No newline at end of file
+
+    /// `clean_inner` output is at most 63 bytes, for ASCII input up to 65 bytes.
+    #[kani::proof]
+    #[kani::unwind(66)]
+    fn verify_clean_inner_truncates_to_max_length() {
+        let input = any_ascii::<{ MAX_LENGTH + 2 }>();
+        kani::assert(
+            TypeIdPrefix::clean_inner_for_proofs(&input, SpecVersion::LATEST).len() <= MAX_LENGTH,
+            "clean_inner output should not exceed 63 characters.",
+        );
+    }
+}
@@ -1,3 +1,6 @@
+//! Property-based tests for validation, sanitization and batch validation.
+
+#![cfg(feature = "alloc")]
 #![doc(hidden)]
 
 use std::convert::TryFrom;
@@ -5,6 +8,7 @@ use std::convert::TryFrom;
 use proptest::prelude::*;
 use proptest::test_runner::{Config, FileFailurePersistence};
 
+use typeid_prefix::batch;
 use typeid_prefix::prelude::*;
 
 mod proofs;
@@ -28,8 +32,8 @@ proptest! {
             prop_assert_eq!(try_from_result.unwrap_err(), ValidationError::IsEmpty);
         } else {
             let is_ascii = input.is_ascii();
-            let starts_with_valid_char = input.chars().next().map_or(false, |c| c.is_ascii_lowercase());
-            let ends_with_valid_char = input.chars().last().map_or(false, |c| c.is_ascii_lowercase());
+            let starts_with_valid_char = input.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+            let ends_with_valid_char = input.chars().last().is_some_and(|c| c.is_ascii_lowercase());
             let contains_only_valid_chars = input.chars().all(|c| c.is_ascii_lowercase() || c == '_');
             if !is_ascii || !starts_with_valid_char || !ends_with_valid_char || !contains_only_valid_chars {
                 prop_assert!(try_from_result.is_err());
@@ -65,8 +69,8 @@ proptest! {
             prop_assert_eq!(try_from_result.unwrap_err(), ValidationError::IsEmpty);
         } else {
             let is_ascii = input.is_ascii();
-            let starts_with_valid_char = input.chars().next().map_or(false, |c| c.is_ascii_lowercase());
-            let ends_with_valid_char = input.chars().last().map_or(false, |c| c.is_ascii_lowercase());
+            let starts_with_valid_char = input.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+            let ends_with_valid_char = input.chars().last().is_some_and(|c| c.is_ascii_lowercase());
             let contains_only_valid_chars = input.chars().all(|c| c.is_ascii_lowercase() || c == '_');
             if !is_ascii || !starts_with_valid_char || !ends_with_valid_char || !contains_only_valid_chars {
                 prop_assert!(try_from_result.is_err());
@@ -103,4 +107,87 @@ proptest! {
             prop_assert!(TypeIdPrefix::try_from(cleaned.as_str()).is_ok());
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_typeidprefix_canonicalize(input in "\\PC{0,100}") {
+        let canonical = TypeIdPrefix::canonicalize(&input);
+        prop_assert!(TypeIdPrefix::is_canonical(canonical.as_str()));
+        prop_assert_eq!(&TypeIdPrefix::canonicalize(canonical.as_str()), &canonical);
+        prop_assert_eq!(TypeIdPrefix::is_canonical(&input), canonical == input);
+        if let Ok(prefix) = TypeIdPrefix::try_from(input.as_str()) {
+            prop_assert_eq!(canonical, prefix);
+        }
+    }
+
+    #[test]
+    fn test_typeidprefix_try_from_bytes(input in proptest::collection::vec(any::<u8>(), 0..100)) {
+        let from_bytes = TypeIdPrefix::try_from_bytes(&input);
+        match std::str::from_utf8(&input) {
+            // Valid UTF-8 must behave exactly like the &str path
+            Ok(s) => prop_assert_eq!(from_bytes, TypeIdPrefix::try_from(s)),
+            // Anything else is never a valid prefix
+            Err(_) => prop_assert!(from_bytes.is_err()),
+        }
+    }
+
+    #[test]
+    fn test_batch_matches_try_from(inputs in proptest::collection::vec("[a-z_A-Z0-9 \\-é]{0,70}", 0..20)) {
+        let checked = batch::check_many(&inputs);
+        let validated = batch::validate_many(&inputs);
+        prop_assert_eq!(checked.len(), inputs.len());
+        for ((input, checked), validated) in inputs.iter().zip(checked).zip(validated) {
+            let expected = TypeIdPrefix::try_from(input.as_str());
+            prop_assert_eq!(checked, expected.as_ref().map(|_| ()).map_err(|e| *e));
+            prop_assert_eq!(validated, expected);
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod strategies {
+    use proptest::prelude::*;
+    use proptest::sample::select;
+    use proptest::test_runner::{Config, FileFailurePersistence};
+
+    use typeid_prefix::prelude::*;
+    use typeid_prefix::strategies::{any_invalid_prefix_for, any_sanitizable_input, any_valid_prefix};
+
+    const ERRORS: [ValidationError; 7] = [
+        ValidationError::ExceedsMaxLength,
+        ValidationError::ContainsInvalidCharacters,
+        ValidationError::StartsWithUnderscore,
+        ValidationError::EndsWithUnderscore,
+        ValidationError::InvalidStartCharacter,
+        ValidationError::InvalidEndCharacter,
+        ValidationError::IsEmpty,
+    ];
+
+    proptest! {
+        #![proptest_config(Config {
+            cases: 1000,
+            failure_persistence: Some(Box::new(FileFailurePersistence::Off)),
+            .. Config::default()
+        })]
+        #[test]
+        fn test_valid_prefixes_are_accepted(prefix in any_valid_prefix()) {
+            prop_assert_eq!(TypeIdPrefix::try_from(prefix.as_str()), Ok(prefix.clone()));
+            prop_assert_eq!(TypeIdPrefix::try_from_bytes(prefix.as_bytes()), Ok(prefix.clone()));
+            prop_assert_eq!(prefix.as_str().create_prefix_sanitized(), prefix);
+        }
+
+        #[test]
+        fn test_invalid_prefixes_report_their_error(
+            (error, input) in select(ERRORS.to_vec()).prop_flat_map(|error| (Just(error), any_invalid_prefix_for(error)))
+        ) {
+            prop_assert_eq!(TypeIdPrefix::try_from(input.as_str()), Err(error));
+            prop_assert_eq!(TypeIdPrefixRef::try_from(input.as_str()), Err(error));
+        }
+
+        #[test]
+        fn test_sanitizable_inputs_sanitize_to_valid_prefixes(input in any_sanitizable_input()) {
+            let sanitized = input.create_prefix_sanitized();
+            prop_assert!(!sanitized.is_empty());
+            prop_assert!(TypeIdPrefix::try_from(sanitized.as_str()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Tests for the `quickcheck` implementation of `Arbitrary` for `TypeIdPrefix`.
+//!
+//! This module checks that generated and shrunk prefixes are always valid, and that failing
+//! properties minimize to short prefixes.
+
+#![cfg(feature = "quickcheck")]
+#![doc(hidden)]
+
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+use typeid_prefix::TypeIdPrefix;
+
+/// Shrinks `prefix` the way `quickcheck` does, keeping the first candidate that still fails.
+fn minimize(mut prefix: TypeIdPrefix, fails: impl Fn(&TypeIdPrefix) -> bool) -> TypeIdPrefix {
+    while let Some(smaller) = prefix.shrink().find(|candidate| fails(candidate)) {
+        prefix = smaller;
+    }
+    prefix
+}
+
+#[test]
+fn test_arbitrary_and_shrink_are_valid() {
+    // quickcheck passes generated values by value
+    #[allow(clippy::needless_pass_by_value)]
+    fn property(prefix: TypeIdPrefix) -> bool {
+        TypeIdPrefix::try_from(prefix.as_str()).is_ok()
+            && prefix.shrink().all(|shrunk| TypeIdPrefix::try_from(shrunk.as_str()).is_ok())
+    }
+    QuickCheck::new().tests(1000).quickcheck(property as fn(TypeIdPrefix) -> bool);
+}
+
+#[test]
+fn test_arbitrary_respects_size() {
+    let mut g = Gen::new(5);
+    for _ in 0..100 {
+        assert!(TypeIdPrefix::arbitrary(&mut g).len() <= 5);
+    }
+
+    let mut g = Gen::new(1000);
+    for _ in 0..100 {
+        assert!(TypeIdPrefix::arbitrary(&mut g).len() <= 63);
+    }
+}
+
+#[test]
+fn test_shrinks_to_minimal_prefixes() {
+    let long = TypeIdPrefix::try_from("order_line_item_with_extra_segments").unwrap();
+    assert_eq!(minimize(long.clone(), |_| true), TypeIdPrefix::try_from("a").unwrap());
+    assert_eq!(minimize(long.clone(), |p| p.contains('_')), TypeIdPrefix::try_from("a_a").unwrap());
+    assert_eq!(minimize(long, |p| p.len() >= 5), TypeIdPrefix::try_from("aaaaa").unwrap());
+    assert_eq!(TypeIdPrefix::try_from("a").unwrap().shrink().count(), 0);
+}
@@ -0,0 +1,80 @@
+//! Tests for the parallel batch functions.
+//!
+//! This module tests that `validate_batch` and `sanitize_batch` agree with the
+//! sequential `PrefixFactory` methods and keep results in input order.
+
+#![cfg(feature = "rayon")]
+#![doc(hidden)]
+
+use typeid_prefix::batch;
+use typeid_prefix::prelude::*;
+
+fn column() -> Vec<String> {
+    (0..10_000)
+        .map(|i| match i % 4 {
+            0 => format!("user_{}", "x".repeat(i % 50 + 1)),
+            1 => format!("Invalid Input {i}"),
+            2 => i.to_string(),
+            _ => "_order_item_".to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_validate_batch_matches_sequential() {
+    let column = column();
+    let results = batch::validate_batch(&column);
+    assert_eq!(results.len(), column.len());
+    for (input, result) in column.iter().zip(results) {
+        assert_eq!(result, input.try_create_prefix(), "Mismatch for {input:?}");
+    }
+}
+
+#[test]
+fn test_sanitize_batch_matches_sequential() {
+    let column = column();
+    let results = batch::sanitize_batch(column.clone());
+    assert_eq!(results.len(), column.len());
+    for (input, result) in column.iter().zip(results) {
+        match input.create_prefix_sanitized().into_prefix() {
+            Some(sanitized) => assert_eq!(result, Ok(sanitized), "Mismatch for {input:?}"),
+            None => assert_eq!(result, Err(ValidationError::IsEmpty), "Mismatch for {input:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_batches_accept_string_slices() {
+    let inputs: &[&str] = &["user", "Order Item", ""];
+    assert_eq!(
+        batch::validate_batch(inputs),
+        vec![
+            Ok(TypeIdPrefix::try_from("user").unwrap()),
+            Err(ValidationError::InvalidStartCharacter),
+            Err(ValidationError::IsEmpty),
+        ]
+    );
+    assert_eq!(
+        batch::sanitize_batch(inputs),
+        vec![
+            Ok(TypeIdPrefix::try_from("user").unwrap()),
+            Ok(TypeIdPrefix::try_from("orderitem").unwrap()),
+            Err(ValidationError::IsEmpty),
+        ]
+    );
+}
+
+#[test]
+fn test_batches_accept_sequential_iterators() {
+    let text = "user\nOrder Item\n_order_";
+    assert_eq!(batch::validate_batch(text.lines()), batch::validate_batch(["user", "Order Item", "_order_"]));
+
+    let sanitized = batch::sanitize_batch(text.lines().filter(|line| !line.starts_with('_')));
+    assert_eq!(
+        sanitized,
+        vec![
+            Ok(TypeIdPrefix::try_from("user").unwrap()),
+            Ok(TypeIdPrefix::try_from("orderitem").unwrap()),
+        ]
+    );
+}
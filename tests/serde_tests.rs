@@ -1,15 +1,14 @@
-//! Tests for serde serialization and deserialization of TypeIdPrefix.
+//! Tests for serde serialization and deserialization of `TypeIdPrefix`.
 //!
-//! This module tests the serde implementation for the TypeIdPrefix struct,
+//! This module tests the serde implementation for the `TypeIdPrefix` struct,
 //! ensuring that it correctly serializes to and deserializes from strings
-//! while maintaining the validation rules of the TypeID specification.
+//! while maintaining the validation rules of the `TypeID` specification.
 
-#![cfg(feature = "serde")]
+#![cfg(all(feature = "serde", feature = "alloc"))]
 #![doc(hidden)]
 
-use std::convert::TryFrom;
-use serde_json;
-use typeid_prefix::TypeIdPrefix;
+use serde::Deserialize;
+use typeid_prefix::{OptionalPrefix, SharedTypeIdPrefix, TypeIdPrefix, TypeIdPrefixRef};
 
 #[test]
 fn test_serialize_typeidprefix() {
@@ -50,7 +49,7 @@ fn test_deserialize_invalid_typeidprefix() {
     for invalid_json in invalid_cases {
         // Deserialization should fail
         let result: Result<TypeIdPrefix, _> = serde_json::from_str(invalid_json);
-        assert!(result.is_err(), "Should fail to deserialize: {}", invalid_json);
+        assert!(result.is_err(), "Should fail to deserialize: {invalid_json}");
     }
 }
 
@@ -71,16 +70,126 @@ fn test_roundtrip_serialization() {
 
 #[test]
 fn test_empty_string() {
-    // Empty string is valid according to the validation rules
+    // A TypeIdPrefix is never empty, so the empty string is rejected
     let json = "\"\"";
-    
-    // Deserialize
-    let prefix: TypeIdPrefix = serde_json::from_str(json).unwrap();
-    
-    // Verify it deserializes correctly
-    assert_eq!(prefix.as_str(), "");
-    
-    // Verify serialization
-    let serialized = serde_json::to_string(&prefix).unwrap();
-    assert_eq!(serialized, "\"\"");
-}
\ No newline at end of file
+    assert!(serde_json::from_str::<TypeIdPrefix>(json).is_err());
+
+    // The empty string is the "no prefix" form of an OptionalPrefix
+    let prefix: OptionalPrefix = serde_json::from_str(json).unwrap();
+    assert_eq!(prefix, OptionalPrefix::none());
+    assert_eq!(serde_json::to_string(&prefix).unwrap(), json);
+
+    // Non-empty input is still validated
+    let prefix: OptionalPrefix = serde_json::from_str("\"valid_prefix\"").unwrap();
+    assert_eq!(prefix, TypeIdPrefix::try_from("valid_prefix").unwrap());
+    assert!(serde_json::from_str::<OptionalPrefix>("\"_invalid\"").is_err());
+}
+#[test]
+fn test_deserialize_typeidprefixref_borrows() {
+    let json = String::from("\"valid_prefix\"");
+
+    // Deserialize without allocating a new string
+    let prefix: TypeIdPrefixRef<'_> = serde_json::from_str(&json).unwrap();
+
+    // Verify it borrows from the input buffer
+    assert_eq!(prefix, "valid_prefix");
+    assert_eq!(prefix.as_ptr(), json[1..].as_ptr());
+
+    // Verify roundtrip
+    assert_eq!(serde_json::to_string(&prefix).unwrap(), json);
+}
+
+#[test]
+fn test_deserialize_invalid_typeidprefixref() {
+    let result: Result<TypeIdPrefixRef<'_>, _> = serde_json::from_str("\"Invalid_Prefix\"");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_deserialize_typeidprefixref_requires_borrowed_input() {
+    // Escaped strings cannot be borrowed from the input, even when the prefix is valid
+    let json = "\"valid\\u005fprefix\"";
+    let err = serde_json::from_str::<TypeIdPrefixRef<'_>>(json).unwrap_err();
+    assert!(err.to_string().contains("deserialize into TypeIdPrefix instead"), "{err}");
+
+    // Neither can strings owned by the deserializer
+    let err = TypeIdPrefixRef::deserialize(serde_json::json!("valid_prefix")).unwrap_err();
+    assert!(err.to_string().contains("deserialize into TypeIdPrefix instead"), "{err}");
+
+    // The owned type accepts the same input
+    let owned: TypeIdPrefix = serde_json::from_str(json).unwrap();
+    assert_eq!(owned.as_str(), "valid_prefix");
+}
+
+#[test]
+fn test_shared_prefix_matches_owned() {
+    let shared = SharedTypeIdPrefix::try_from("valid_prefix").unwrap();
+    let owned = TypeIdPrefix::try_from("valid_prefix").unwrap();
+    assert_eq!(serde_json::to_string(&shared).unwrap(), serde_json::to_string(&owned).unwrap());
+
+    let deserialized: SharedTypeIdPrefix = serde_json::from_str("\"valid_prefix\"").unwrap();
+    assert_eq!(deserialized, shared);
+
+    for json in ["\"Invalid_Prefix\"", "\"_invalid\"", "\"invalid_\"", "42"] {
+        assert!(serde_json::from_str::<SharedTypeIdPrefix>(json).is_err());
+        assert!(serde_json::from_str::<TypeIdPrefix>(json).is_err());
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Modes {
+    #[serde(with = "typeid_prefix::serde_modes::strict")]
+    strict: TypeIdPrefix,
+    #[serde(with = "typeid_prefix::serde_modes::sanitize")]
+    sanitize: TypeIdPrefix,
+    #[serde(with = "typeid_prefix::serde_modes::lowercase")]
+    lowercase: TypeIdPrefix,
+}
+
+fn modes(strict: &str, sanitize: &str, lowercase: &str) -> Result<Modes, serde_json::Error> {
+    let json = serde_json::json!({ "strict": strict, "sanitize": sanitize, "lowercase": lowercase });
+    serde_json::from_value(json)
+}
+
+#[test]
+fn test_serde_modes_accept_valid_prefixes() {
+    let decoded = modes("user", "order_item", "account").unwrap();
+    assert_eq!(decoded.strict, "user");
+    assert_eq!(decoded.sanitize, "order_item");
+    assert_eq!(decoded.lowercase, "account");
+
+    // Every mode serializes the prefix as a plain string
+    let serialized = serde_json::to_string(&decoded).unwrap();
+    assert_eq!(serialized, r#"{"strict":"user","sanitize":"order_item","lowercase":"account"}"#);
+}
+
+#[test]
+fn test_serde_mode_strict_rejects_invalid_input() {
+    for invalid in ["User", "_user", "user_", "order item", ""] {
+        let err = modes(invalid, "user", "user").unwrap_err();
+        assert!(err.to_string().starts_with("Input"), "{invalid:?}: {err}");
+    }
+}
+
+#[test]
+fn test_serde_mode_sanitize_cleans_input() {
+    assert_eq!(modes("user", "Order Item!", "user").unwrap().sanitize, "orderitem");
+    assert_eq!(modes("user", "_Legacy_Kind_", "user").unwrap().sanitize, "legacy_kind");
+    assert_eq!(modes("user", &"a".repeat(70), "user").unwrap().sanitize, "a".repeat(63));
+
+    // Input that sanitizes to nothing is still an error
+    for empty in ["", "123", "___"] {
+        assert!(modes("user", empty, "user").is_err(), "{empty:?}");
+    }
+}
+
+#[test]
+fn test_serde_mode_lowercase_folds_case() {
+    assert_eq!(modes("user", "user", "USER").unwrap().lowercase, "user");
+    assert_eq!(modes("user", "user", "Order_Item").unwrap().lowercase, "order_item");
+
+    // Only case is folded; other invalid input is rejected
+    for invalid in ["Order Item", "_User", "User1", ""] {
+        assert!(modes("user", "user", invalid).is_err(), "{invalid:?}");
+    }
+}